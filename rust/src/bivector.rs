@@ -1,8 +1,9 @@
 // Copyright 2024 Bewusstsein Labs
 
 use std::{
+    cmp::Ordering,
     fmt::Debug,
-    ops::{ Neg, Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign }
+    ops::{ Neg, Add, Mul, Sub }
 };
 use num::traits::{ Num, Float };
 
@@ -16,8 +17,9 @@ use linear_algebra::{
 
 use crate::{
     ops::{
-        //InteriorProduct,
         ExteriorProduct,
+        LeftContraction,
+        ScalarProduct,
         GeometricAdd,
         GeometricSub,
         GeometricProduct
@@ -30,489 +32,170 @@ use crate::{
         YZ,
         YZMut
     },
+    blade::binomial,
+    kvector::KVector,
     trivector::TriVector,
-    //rotor::Rotor,
+    multivector::Multivector,
+    rotor::Rotor
 };
 
-/// A vector type of generic element and size.
+/// A bivector type of generic element and size.
 ///
-#[derive( Clone, Copy, Debug )]
-pub struct BiVector<T, const DIM: usize>( [ T; DIM * ( DIM - 1 ) / 2 ] )
-where
-    T: 'static + Default + Copy + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:;
+/// Stores one component per grade 2 basis blade, C(DIM, 2) in total, ordered lexicographically,
+/// e.g. xy, xz, yz in 3D.
+///
+pub type BiVector<T, const DIM: usize> = KVector<T, DIM, 2>;
 
 impl<T, const DIM: usize> BiVector<T, DIM>
 where
     T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
-    /// Creates a new const [`BiVector`].
+    /// Returns `true` if the [`BiVector`] is simple, i.e. a single plane.
     ///
-    pub const fn new_const( src: [T; DIM * ( DIM - 1 ) / 2] ) -> Self {
-        Self ( src )
-    }
-
-    /// Creates a new [`BiVector`].
+    /// A bivector is simple exactly when B ∧ B vanishes, which always holds below 4D. Simple bivectors
+    /// exponentiate to single plane rotations.
     ///
-    pub fn new( src: [T; DIM * ( DIM - 1 ) / 2] ) -> Self {
-        Self ( src )
-    }
-
-    /// Creates a new zero filled [`BiVector`].
-    ///
-    pub fn zero() -> Self
+    pub fn is_simple( &self, epsilon: T ) -> bool
     where
-        T: Num
+        T: Num + Float,
+        // The KVector ∧ KVector impl is bounded on binomial( DIM, LHS + RHS ), which the compiler does not
+        // unify with binomial( DIM, 4 ), so the grade 4 result needs both.
+        [(); binomial( DIM, 4 )]:,
+        [(); binomial( DIM, 2 + 2 )]:
     {
-        Self ( [T::zero(); DIM * ( DIM - 1 ) / 2] )
+        self.exterior_product( *self ).iter()
+            .all( |a| a.abs() <= epsilon )
     }
 
-    /// Returns an iterator over the elements of the [`BiVector`].
+    /// Splits the [`BiVector`] into orthogonal, commuting simple parts B = B₁ + B₂ with |B₁| ≥ |B₂|.
     ///
-    /// The iterator yields references to the elements of the [`BiVector`] in order.
+    /// With W = ½ B ∧ B = θ₁θ₂ B̂₁B̂₂ the parts are B₁ = ( θ₁² B + W B ) / ( θ₁² - θ₂² ) and B₂ = B - B₁, a simple
+    /// bivector splits into itself and zero. An isoclinic bivector, θ₁ = θ₂, splits in infinitely many ways, B₁ is
+    /// then taken in the plane of the basis vector B moves most and its image. Every bivector up to 5D splits this
+    /// way, from 6D on a bivector can span three planes and B₂ is then not simple.
     ///
-    pub fn iter( &self ) -> impl Iterator<Item = &T> {
-        self.0.iter()
-    }
-
-    /// Returns an iterator over mutable references to the elements of the [`BiVector`].
+    pub fn split( &self ) -> ( Self, Self )
+    where
+        T: Num + Float,
+        [(); 1 << DIM]:
+    {
+        let norm_squared = self.iter().fold( T::zero(), |acc, &a| acc + a * a );
+        if norm_squared == T::zero() {
+            return ( *self, *self );
+        }
+        let two = T::one() + T::one();
+        let b = Multivector::<T, DIM>::from( *self );
+        let w = b.exterior_product( b ) / two;
+        let w_norm_squared = w.iter().fold( T::zero(), |acc, &a| acc + a * a );
+        let discriminant = ( norm_squared * norm_squared - two * two * w_norm_squared ).max( T::zero() ).sqrt();
+        if discriminant > T::epsilon().sqrt() * norm_squared {
+            let theta_squared = ( norm_squared + discriminant ) / two;
+            let first = ( *self * theta_squared + w.geometric_product( b ).bivector() ) / discriminant;
+            return ( first, *self - first );
+        }
+        // Isoclinic, every plane spanned by a vector and its image under B is invariant
+        let ( x, image ) = ( 0..DIM )
+            .map( |i| {
+                let mut x = Multivector::<T, DIM>::zero();
+                x[ 1 << i ] = T::one();
+                ( x, x.left_contraction( b ) )
+            } )
+            .max_by( |( _, lhs ), ( _, rhs )| lhs.scalar_product( *lhs ).partial_cmp( &rhs.scalar_product( *rhs ) ).unwrap_or( Ordering::Equal ) )
+            .unwrap();
+        let plane = x.exterior_product( image ).bivector();
+        let scale = self.iter().zip( plane.iter() ).fold( T::zero(), |acc, ( &a, &b )| acc + a * b ) /
+            plane.iter().fold( T::zero(), |acc, &a| acc + a * a );
+        let first = plane * scale;
+        ( first, *self - first )
+    }
+
+    /// Returns the exponential e^B = cos θ + sin θ B̂ of the [`BiVector`] as a [`Rotor`], θ = |B|.
     ///
-    /// The iterator yields mutable references to the elements of the [`BiVector`] in order.
+    /// A non-simple bivector is first split into its commuting simple parts, see [`BiVector::split`], and
+    /// e^B = e^B₁ e^B₂. e^( -θ B̂ / 2 ) turns by θ in the plane B̂, like [`Rotor::from_plane_angle`].
     ///
-    pub fn iter_mut( &mut self ) -> impl Iterator<Item = &mut T> {
-        self.0.iter_mut()
+    pub fn exp( &self ) -> Rotor<T, DIM>
+    where
+        T: Num + Float,
+        [(); 1 << DIM]:,
+        [(); binomial( DIM, 4 )]:
+    {
+        let ( first, second ) = self.split();
+        let ( a, b ) = ( first.magnitude(), second.magnitude() );
+        let sinc = |theta: T| if theta == T::zero() { T::one() } else { theta.sin() / theta };
+        Rotor::new_even(
+            a.cos() * b.cos(),
+            first * ( sinc( a ) * b.cos() ) + second * ( a.cos() * sinc( b ) ),
+            Multivector::<T, DIM>::from( first ).exterior_product( Multivector::from( second ) ).kvector::<4>() * ( sinc( a ) * sinc( b ) )
+        )
     }
 }
 
 impl<T, const DIM: usize> XY<T> for BiVector<T, DIM>
 where
     T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     fn xy( &self ) -> &T {
-        &self.0[ 0 ]
+        &self[ 0 ]
     }
 }
 
 impl<T, const DIM: usize> XYMut<T> for BiVector<T, DIM>
 where
     T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     fn xy_mut( &mut self ) -> &mut T {
-        &mut self.0[ 0 ]
+        &mut self[ 0 ]
     }
 }
 
 impl<T, const DIM: usize> XZ<T> for BiVector<T, DIM>
 where
     T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     fn xz( &self ) -> &T {
-        &self.0[ 1 ]
+        &self[ 1 ]
     }
 }
 
 impl<T, const DIM: usize> XZMut<T> for BiVector<T, DIM>
 where
     T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     fn xz_mut( &mut self ) -> &mut T {
-        &mut self.0[ 1 ]
+        &mut self[ 1 ]
     }
 }
 
 impl<T, const DIM: usize> YZ<T> for BiVector<T, DIM>
 where
     T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     fn yz( &self ) -> &T {
-        &self.0[ 2 ]
+        &self[ 2 ]
     }
 }
 
 impl<T, const DIM: usize> YZMut<T> for BiVector<T, DIM>
 where
     T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     fn yz_mut( &mut self ) -> &mut T {
-        &mut self.0[ 2 ]
-    }
-}
-
-impl<T, const DIM: usize> Deref for BiVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Target = [T; DIM * ( DIM - 1 ) / 2];
-
-    fn deref( &self ) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl<T, const DIM: usize> DerefMut for BiVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn deref_mut( &mut self ) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
-impl<T, const DIM: usize> Index<usize> for BiVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = T;
-
-    fn index( &self, index: usize ) -> &Self::Output {
-        &self.0[ index ]
-    }
-}
-
-impl<T, const DIM: usize> IndexMut<usize> for BiVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn index_mut( &mut self, index: usize ) -> &mut Self::Output {
-        &mut self.0[ index ]
-    }
-}
-
-impl<T, const DIM: usize> Default for BiVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn default() -> Self {
-        Self ( [T::default(); DIM * ( DIM - 1 ) / 2] )
-    }
-}
-
-impl<T, const DIM: usize> From<[T; DIM * ( DIM - 1 ) / 2]> for BiVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn from( src: [T; DIM * ( DIM - 1 ) / 2] ) -> Self {
-        Self ( src )
-    }
-}
-
-impl<T, const DIM: usize> PartialEq for BiVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug + PartialEq,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn eq( &self, other: &Self ) -> bool {
-        self.0 == other.0
-    }
-}
-
-impl<T, const DIM: usize> Neg for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Neg<Output = T>,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = Self;
-
-    fn neg( mut self ) -> Self::Output {
-        self.iter_mut()
-            .for_each( |a| *a = -*a );
-        self
-    }
-}
-
-impl<T, const DIM: usize> Add for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Add<Output = T>,
-    Self: Clone,
-    [(); DIM * ( DIM - 1 ) / 2]:
-{
-    type Output = Self;
-
-    fn add( mut self, other: Self ) -> Self::Output {
-        self.iter_mut().zip( other.iter() )
-            .for_each( |( a, &b )| *a = *a + b );
-        self
-    }
-}
-
-impl<T, const DIM: usize> Sub for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Sub<Output = T>,
-    Self: Clone,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = Self;
-
-    fn sub( mut self, other: Self ) -> Self::Output {
-        self.iter_mut().zip( other.iter() )
-            .for_each( |( a, &b )| *a = *a - b );
-        self
-    }
-}
-
-impl<T, const DIM: usize> Add<T> for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Add<Output = T>,
-    Self: Clone,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = Self;
-
-    fn add( mut self, scalar: T ) -> Self::Output {
-        self.iter_mut()
-            .for_each( |a| *a = *a + scalar );
-        self
-    }
-}
-
-impl<T, const DIM: usize> Sub<T> for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Sub<Output = T>,
-    Self: Clone,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = Self;
-
-    fn sub( mut self, scalar: T ) -> Self::Output {
-        self.iter_mut()
-            .for_each( |a| *a = *a - scalar );
-        self
-    }
-}
-
-impl<T, const DIM: usize> Mul<T> for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Mul<Output = T>,
-    Self: Clone,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = Self;
-
-    fn mul( mut self, scalar: T ) -> Self::Output {
-        self.iter_mut()
-            .for_each( |a| *a = *a * scalar );
-        self
-    }
-}
-
-impl<T, const DIM: usize> Div<T> for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Div<Output = T>,
-    Self: Clone,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = Self;
-
-    fn div( mut self, scalar: T ) -> Self::Output {
-        self.iter_mut()
-            .for_each( |a| *a = *a / scalar );
-        self
-    }
-}
-
-impl<T, const DIM: usize> AddAssign for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + AddAssign,
-    [(); DIM * ( DIM - 1 ) / 2]:
-{
-    fn add_assign( &mut self, other: Self ) {
-        self.iter_mut().zip( other.iter() )
-            .for_each( |( a, &b )| *a += b );
-    }
-}
-
-impl<T, const DIM: usize> SubAssign for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + SubAssign,
-    [(); DIM * ( DIM - 1 ) / 2]:
-{
-    fn sub_assign( &mut self, other: Self ) {
-        self.iter_mut().zip( other.iter() )
-            .for_each( |( a, &b )| *a -= b );
-    }
-}
-
-impl<T, const DIM: usize> AddAssign<T> for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + AddAssign,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn add_assign( &mut self, scalar: T ) {
-        self.iter_mut()
-            .for_each( |a| *a += scalar );
-    }
-}
-
-impl<T, const DIM: usize> SubAssign<T> for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + SubAssign,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn sub_assign( &mut self, scalar: T ) {
-        self.iter_mut()
-            .for_each( |a| *a -= scalar );
-    }
-}
-
-impl<T, const DIM: usize> MulAssign<T> for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + MulAssign,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn mul_assign( &mut self, scalar: T ) {
-        self.iter_mut()
-            .for_each( |a| *a *= scalar );
-    }
-}
-
-impl<T, const DIM: usize> DivAssign<T> for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + DivAssign,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn div_assign( &mut self, scalar: T ) {
-        self.iter_mut()
-            .for_each( |a| *a /= scalar );
-    }
-}
-
-impl<T, const DIM: usize> Add<T> for &BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Add<Output = T>,
-    Self: Clone,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = BiVector<T, DIM>;
-
-    fn add( self, scalar: T ) -> Self::Output {
-        let mut result = BiVector::<T, DIM>::default();
-        self.iter().zip( result.iter_mut() )
-            .for_each( |( &a, c )| *c = a + scalar );
-        result
-    }
-}
-
-impl<T, const DIM: usize> Sub<T> for &BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Sub<Output = T>,
-    Self: Clone,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = BiVector<T, DIM>;
-
-    fn sub( self, scalar: T ) -> Self::Output {
-        let mut result = BiVector::<T, DIM>::default();
-        self.iter().zip( result.iter_mut() )
-            .for_each( |( &a, c )| *c = a - scalar );
-        result
-    }
-}
-
-impl<T, const DIM: usize> Mul<T> for &BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Mul<Output = T>,
-    Self: Clone,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = BiVector<T, DIM>;
-
-    fn mul( self, scalar: T ) -> Self::Output {
-        let mut result = BiVector::<T, DIM>::default();
-        self.iter().zip( result.iter_mut() )
-            .for_each( |( &a, c )| *c = a * scalar );
-        result
-    }
-}
-
-impl<T, const DIM: usize> Div<T> for &BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Div<Output = T>,
-    Self: Clone,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = BiVector<T, DIM>;
-
-    fn div( self, scalar: T ) -> Self::Output {
-        let mut result = BiVector::<T, DIM>::default();
-        self.iter().zip( result.iter_mut() )
-            .for_each( |( &a, c )| *c = a / scalar );
-        result
-    }
-}
-
-impl<T, const DIM: usize> AddAssign<T> for &mut BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + AddAssign,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn add_assign( &mut self, scalar: T ) {
-        self.iter_mut()
-            .for_each( |a| *a += scalar );
-    }
-}
-
-impl<T, const DIM: usize> SubAssign<T> for &mut BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + SubAssign,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn sub_assign( &mut self, scalar: T ) {
-        self.iter_mut()
-            .for_each( |a| *a -= scalar );
-    }
-}
-
-impl<T, const DIM: usize> MulAssign<T> for &mut BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + MulAssign,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn mul_assign( &mut self, scalar: T ) {
-        self.iter_mut()
-            .for_each( |a| *a *= scalar );
-    }
-}
-
-impl<T, const DIM: usize> DivAssign<T> for &mut BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + DivAssign,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    fn div_assign( &mut self, scalar: T ) {
-        self.iter_mut()
-            .for_each( |a| *a /= scalar );
-    }
-}
-
-impl<T, const DIM: usize> Magnitude for BiVector<T, DIM>
-where
-    T: Default + Copy + Debug + Div<Output = T> + Num + Float,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = T;
-
-    fn magnitude( &self ) -> Self::Output {
-        self.0.iter().fold( T::zero(), |acc, &x| acc + x * x ).sqrt()
+        &mut self[ 2 ]
     }
 }
 
 impl<T, const DIM: usize> InnerProduct for BiVector<T, DIM>
 where
     T: Default + Copy + Debug + Mul<Output = T> + Add<Output = T> + Neg<Output = T>,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     type Output = T;
 
@@ -522,54 +205,25 @@ where
     }
 }
 
+/// BiVector<T, DIM> · Vector<T, DIM>
+///
+/// The grade 1 part of the geometric product, e_ij e_j = e_i and e_ij e_i = -e_j.
+///
 impl<T, const DIM: usize> InnerProduct<Vector<T, DIM>> for BiVector<T, DIM>
 where
-    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T>,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    T: Default + std::fmt::Debug + Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    [(); binomial( DIM, 2 )]:
 {
-    type Output = Vector<T, { DIM * ( DIM - 1 ) / 2 }>;
+    type Output = Vector<T, DIM>;
 
     fn inner_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
-        let mut res = Vector::<T, { DIM * ( DIM - 1 ) / 2 }>::default();
-        for i in 0..( DIM - 1 ) {
-            for j in ( i + 1 )..DIM {
-                res[ j ] = ( self[ i ] * rhs[ j ] ) - ( self[ i ] * rhs[ i ] );
-            }
-        }
-        res
-    }
-}
-
-impl<T, const DIM: usize> ExteriorProduct<Vector<T, DIM>> for BiVector<T, DIM>
-where
-    T: Default + std::fmt::Debug + Copy + AddAssign + Mul<Output = T>,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = TriVector<T, DIM>;
-
-    fn exterior_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
-        let mut res = T::default();
-        for i in 0..( DIM - 1 ) {
+        let mut res = Vector::<T, DIM>::default();
+        let mut k = 0;
+        for i in 0..DIM {
             for j in ( i + 1 )..DIM {
-                res += self[ j ] * rhs[ i ];
-            }
-        }
-        TriVector::new( res )
-    }
-}
-
-impl<T, const DIM: usize> ExteriorProduct for BiVector<T, DIM>
-where
-    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T>,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
-{
-    type Output = BiVector<T, DIM>;
-
-    fn exterior_product( self, rhs: BiVector<T, DIM> ) -> Self::Output {
-        let mut res = BiVector::<T, DIM>::default();
-        for i in 0..( DIM - 1 ) {
-            for j in ( i + 1 )..DIM {
-                res[ j ] = self[ i ] * rhs[ j ] - self[ j ] * rhs[ i ];
+                res[ i ] = res[ i ] + self[ k ] * rhs[ j ];
+                res[ j ] = res[ j ] - self[ k ] * rhs[ i ];
+                k += 1;
             }
         }
         res
@@ -579,7 +233,7 @@ where
 impl<T, const DIM: usize> GeometricAdd<Vector<T, DIM>> for BiVector<T, DIM>
 where
     T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Num,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     type Output = ( Vector<T, DIM>, BiVector<T, DIM> );
 
@@ -591,7 +245,7 @@ where
 impl<T, const DIM: usize> GeometricSub<Vector<T, DIM>> for BiVector<T, DIM>
 where
     T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Num,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     type Output = ( Vector<T, DIM>, BiVector<T, DIM> );
 
@@ -600,27 +254,41 @@ where
     }
 }
 
+/// BiVector<T, DIM> * Vector<T, DIM>
+///
+/// Grade 1 and grade 3.
+///
 impl<T, const DIM: usize> GeometricProduct<Vector<T, DIM>> for BiVector<T, DIM>
 where
-    T: Default + std::fmt::Debug + Copy + AddAssign + Mul<Output = T> + Num,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    T: Default + std::fmt::Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 3 )]:,
+    // The KVector ∧ Vector impl is bounded on binomial( DIM, GRADE + 1 ), which the compiler does not
+    // unify with binomial( DIM, 3 ), so the output needs both.
+    [(); binomial( DIM, 2 + 1 )]:
 {
-    type Output = ( Vector<T, { DIM * ( DIM - 1 ) / 2 }>, TriVector<T, DIM> );
+    type Output = ( Vector<T, DIM>, TriVector<T, DIM> );
 
     fn geometric_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
         ( self.inner_product( rhs ), self.exterior_product( rhs ) )
     }
 }
 
+/// BiVector<T, DIM> * BiVector<T, DIM>
+///
+/// Grade 0, grade 2 and, from 4D on, grade 4.
+///
 impl<T, const DIM: usize> GeometricProduct for BiVector<T, DIM>
 where
-    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Num,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    T: Default + std::fmt::Debug + Copy + Num,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:
 {
-    type Output = ( T, BiVector<T, DIM> );
+    type Output = Multivector<T, DIM>;
 
     fn geometric_product( self, rhs: BiVector<T, DIM> ) -> Self::Output {
-        ( self.inner_product( rhs ), self.exterior_product( rhs ) )
+        Multivector::from( self ).geometric_product( rhs )
     }
 }
 
@@ -631,8 +299,17 @@ pub type BiVector4<T> = BiVector<T, 4>;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use linear_algebra::vector::Vector;
-    use crate::ops::ExteriorProduct;
+    use linear_algebra::{
+        ops::InnerProduct,
+        vector::Vector
+    };
+    use crate::{
+        ops::{
+            ExteriorProduct,
+            GeometricProduct
+        },
+        quadvector::QuadVector
+    };
 
     #[test]
     fn exterior_product_2() {
@@ -685,4 +362,132 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn exterior_product_vector_bivector_4() {
+        let lhs = Vector::<f64, 4>::new([ 4.0, 5.0, 6.0, 12.0 ]);
+        let rhs = BiVector::<f64, 4>::new([ 8.0, 3.0, 1.0, 2.0, 7.0, 9.0 ]); // xy, xz, xw, yz, yw, zw
+
+        let result = lhs.exterior_product( rhs );
+
+        assert_eq!(
+            result,
+            TriVector::<f64, 4>::new([
+                lhs[ 0 ] * rhs[ 3 ] - lhs[ 1 ] * rhs[ 1 ] + lhs[ 2 ] * rhs[ 0 ], // xyz
+                lhs[ 0 ] * rhs[ 4 ] - lhs[ 1 ] * rhs[ 2 ] + lhs[ 3 ] * rhs[ 0 ], // xyw
+                lhs[ 0 ] * rhs[ 5 ] - lhs[ 2 ] * rhs[ 2 ] + lhs[ 3 ] * rhs[ 1 ], // xzw
+                lhs[ 1 ] * rhs[ 5 ] - lhs[ 2 ] * rhs[ 4 ] + lhs[ 3 ] * rhs[ 3 ], // yzw
+            ])
+        );
+    }
+
+    #[test]
+    fn inner_product_vector_2() {
+        let lhs = BiVector::<f64, 2>::new([ 3.0 ]); // xy
+        let rhs = Vector::<f64, 2>::new([ 4.0, 5.0 ]);
+
+        let result = lhs.inner_product( rhs );
+
+        assert_eq!(
+            result,
+            Vector::<f64, 2>::new([
+                lhs[ 0 ] * rhs[ 1 ], // x
+                -lhs[ 0 ] * rhs[ 0 ], // y
+            ])
+        );
+    }
+
+    #[test]
+    fn inner_product_vector_3() {
+        let lhs = BiVector::<f64, 3>::new([ 8.0, 3.0, 1.0 ]); // xy, xz, yz
+        let rhs = Vector::<f64, 3>::new([ 4.0, 5.0, 6.0 ]);
+
+        let result = lhs.inner_product( rhs );
+
+        assert_eq!(
+            result,
+            Vector::<f64, 3>::new([
+                lhs[ 0 ] * rhs[ 1 ] + lhs[ 1 ] * rhs[ 2 ], // x
+                -lhs[ 0 ] * rhs[ 0 ] + lhs[ 2 ] * rhs[ 2 ], // y
+                -lhs[ 1 ] * rhs[ 0 ] - lhs[ 2 ] * rhs[ 1 ], // z
+            ])
+        );
+    }
+
+    #[test]
+    fn inner_product_vector_4() {
+        let lhs = BiVector::<f64, 4>::new([ 8.0, 3.0, 1.0, 2.0, 7.0, 9.0 ]); // xy, xz, xw, yz, yw, zw
+        let rhs = Vector::<f64, 4>::new([ 4.0, 5.0, 6.0, 12.0 ]);
+
+        let result = lhs.inner_product( rhs );
+
+        assert_eq!(
+            result,
+            Vector::<f64, 4>::new([
+                lhs[ 0 ] * rhs[ 1 ] + lhs[ 1 ] * rhs[ 2 ] + lhs[ 2 ] * rhs[ 3 ], // x
+                -lhs[ 0 ] * rhs[ 0 ] + lhs[ 3 ] * rhs[ 2 ] + lhs[ 4 ] * rhs[ 3 ], // y
+                -lhs[ 1 ] * rhs[ 0 ] - lhs[ 3 ] * rhs[ 1 ] + lhs[ 5 ] * rhs[ 3 ], // z
+                -lhs[ 2 ] * rhs[ 0 ] - lhs[ 4 ] * rhs[ 1 ] - lhs[ 5 ] * rhs[ 2 ], // w
+            ])
+        );
+    }
+
+    #[test]
+    fn geometric_product_vector_3() {
+        let lhs = BiVector::<f64, 3>::new([ 8.0, 3.0, 1.0 ]); // xy, xz, yz
+        let rhs = Vector::<f64, 3>::new([ 4.0, 5.0, 6.0 ]);
+
+        let result = lhs.geometric_product( rhs );
+
+        assert_eq!(
+            result,
+            (
+                Vector::<f64, 3>::new([
+                    lhs[ 0 ] * rhs[ 1 ] + lhs[ 1 ] * rhs[ 2 ], // x
+                    -lhs[ 0 ] * rhs[ 0 ] + lhs[ 2 ] * rhs[ 2 ], // y
+                    -lhs[ 1 ] * rhs[ 0 ] - lhs[ 2 ] * rhs[ 1 ], // z
+                ]),
+                TriVector::<f64, 3>::new([
+                    lhs[ 0 ] * rhs[ 2 ] - lhs[ 1 ] * rhs[ 1 ] + lhs[ 2 ] * rhs[ 0 ], // xyz
+                ])
+            )
+        );
+    }
+
+    #[test]
+    fn exterior_product_bivector_3() {
+        let lhs = BiVector::<f64, 3>::new([ 8.0, 3.0, 1.0 ]);
+        let rhs = BiVector::<f64, 3>::new([ 4.0, 5.0, 6.0 ]);
+
+        let result = lhs.exterior_product( rhs );
+
+        assert_eq!( result, QuadVector::<f64, 3>::new([]) );
+        assert!( lhs.is_simple( 0.0 ) );
+    }
+
+    #[test]
+    fn exterior_product_bivector_4() {
+        let lhs = BiVector::<f64, 4>::new([ 8.0, 3.0, 1.0, 2.0, 7.0, 9.0 ]); // xy, xz, xw, yz, yw, zw
+        let rhs = BiVector::<f64, 4>::new([ 4.0, 5.0, 6.0, 12.0, 2.0, 1.0 ]);
+
+        let result = lhs.exterior_product( rhs );
+
+        assert_eq!(
+            result,
+            QuadVector::<f64, 4>::new([
+                lhs[ 0 ] * rhs[ 5 ] - lhs[ 1 ] * rhs[ 4 ] + lhs[ 2 ] * rhs[ 3 ]
+              + lhs[ 3 ] * rhs[ 2 ] - lhs[ 4 ] * rhs[ 1 ] + lhs[ 5 ] * rhs[ 0 ], // xyzw
+            ])
+        );
+    }
+
+    #[test]
+    fn is_simple_4() {
+        let xy = Vector::<f64, 4>::new([ 1.0, 0.0, 0.0, 0.0 ]).exterior_product( Vector::<f64, 4>::new([ 1.0, 2.0, 0.0, 0.0 ]) );
+        let zw = Vector::<f64, 4>::new([ 0.0, 0.0, 3.0, 0.0 ]).exterior_product( Vector::<f64, 4>::new([ 0.0, 0.0, 1.0, 1.0 ]) );
+
+        assert!( xy.is_simple( 1e-12 ) );
+        assert!( zw.is_simple( 1e-12 ) );
+        assert!( !( xy + zw ).is_simple( 1e-12 ) );
+    }
 }
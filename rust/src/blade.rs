@@ -0,0 +1,135 @@
+// Copyright 2024 Bewusstsein Labs
+
+/// Returns the binomial coefficient `n` choose `k`.
+///
+/// This is the number of basis blades of grade `k` in a `n` dimensional algebra.
+///
+pub const fn binomial( n: usize, k: usize ) -> usize {
+    if k > n {
+        return 0;
+    }
+    let mut res = 1;
+    let mut i = 0;
+    while i < k {
+        res = res * ( n - i ) / ( i + 1 );
+        i += 1;
+    }
+    res
+}
+
+/// Returns the grade of the basis blade identified by `mask`.
+///
+/// A basis blade e_i ∧ e_j ∧ ... is identified by the bitmask whose set bits are i, j, ...
+///
+pub const fn grade( mask: usize ) -> usize {
+    mask.count_ones() as usize
+}
+
+/// Returns the bitmask of the `index`-th basis blade of grade `grade` in a `dim` dimensional algebra.
+///
+/// Blades of the same grade are ordered lexicographically by their basis indices, which is the order
+/// the graded types store their components in, e.g. xy, xz, xw, yz, yw, zw in 4D.
+///
+pub const fn blade_mask( dim: usize, grade: usize, index: usize ) -> usize {
+    let mut mask = 0;
+    let mut rank = index;
+    let mut remaining = grade;
+    let mut i = 0;
+    while remaining > 0 && i < dim {
+        let count = binomial( dim - i - 1, remaining - 1 );
+        if rank < count {
+            mask |= 1 << i;
+            remaining -= 1;
+        } else {
+            rank -= count;
+        }
+        i += 1;
+    }
+    mask
+}
+
+/// Returns the lexicographic index of the basis blade `mask` among the blades of the same grade.
+///
+/// This is the inverse of [`blade_mask`].
+///
+pub const fn blade_index( dim: usize, mask: usize ) -> usize {
+    let mut index = 0;
+    let mut remaining = grade( mask );
+    let mut i = 0;
+    while remaining > 0 && i < dim {
+        if mask & ( 1 << i ) != 0 {
+            remaining -= 1;
+        } else {
+            index += binomial( dim - i - 1, remaining - 1 );
+        }
+        i += 1;
+    }
+    index
+}
+
+/// Returns `true` if reordering the basis vectors of the product of blades `a` and `b` into
+/// canonical order takes an odd number of swaps.
+///
+pub const fn reorder_negates( a: usize, b: usize ) -> bool {
+    let mut a = a >> 1;
+    let mut swaps = 0;
+    while a != 0 {
+        swaps += ( a & b ).count_ones();
+        a >>= 1;
+    }
+    swaps & 1 == 1
+}
+
+/// Returns `true` if the reverse negates blades of grade `grade`, i.e. if k(k - 1)/2 is odd.
+///
+pub const fn reverse_negates( grade: usize ) -> bool {
+    grade % 4 == 2 || grade % 4 == 3
+}
+
+/// Returns `true` if the grade involution negates blades of grade `grade`, i.e. if k is odd.
+///
+pub const fn involution_negates( grade: usize ) -> bool {
+    grade % 2 == 1
+}
+
+/// Returns `true` if the Clifford conjugate negates blades of grade `grade`, i.e. if k(k + 1)/2 is odd.
+///
+pub const fn conjugate_negates( grade: usize ) -> bool {
+    grade % 4 == 1 || grade % 4 == 2
+}
+
+/// Returns the bitmask of the pseudoscalar of a `dim` dimensional algebra, i.e. the blade containing every basis vector.
+///
+pub const fn pseudoscalar_mask( dim: usize ) -> usize {
+    ( 1 << dim ) - 1
+}
+
+/// Returns `true` if the dual e_a I⁻¹ of the basis blade `mask` in a `dim` dimensional Euclidean algebra
+/// is the negative of its complement blade.
+///
+pub const fn dual_negates( dim: usize, mask: usize ) -> bool {
+    reverse_negates( dim ) != reorder_negates( mask, pseudoscalar_mask( dim ) )
+}
+
+/// Returns `true` if the undual e_a I of the basis blade `mask` in a `dim` dimensional algebra
+/// is the negative of its complement blade.
+///
+pub const fn undual_negates( dim: usize, mask: usize ) -> bool {
+    reorder_negates( mask, pseudoscalar_mask( dim ) )
+}
+
+/// Returns `true` if the Hodge star of the basis blade `mask` in a `dim` dimensional algebra is the
+/// negative of its complement blade, i.e. if e_a ∧ e_(complement) = -I.
+///
+pub const fn hodge_negates( dim: usize, mask: usize ) -> bool {
+    reorder_negates( mask, pseudoscalar_mask( dim ) ^ mask )
+}
+
+/// Returns `true` if the regressive product (e_a* ∧ e_b*) I of the basis blades `a` and `b` in a `dim`
+/// dimensional algebra is -e_(a ∩ b). Only meaningful if `a` and `b` together contain every basis vector.
+///
+pub const fn regressive_negates( dim: usize, a: usize, b: usize ) -> bool {
+    let ca = pseudoscalar_mask( dim ) ^ a;
+    let cb = pseudoscalar_mask( dim ) ^ b;
+    dual_negates( dim, a ) ^ dual_negates( dim, b ) ^ reorder_negates( ca, cb ) ^ undual_negates( dim, ca | cb )
+}
@@ -0,0 +1,15 @@
+// Copyright 2024 Bewusstsein Labs
+
+use thiserror::Error;
+
+/// The errors of the conversions into rotors.
+///
+#[derive( Debug, Error, Clone, Copy, PartialEq, Eq )]
+pub enum Error {
+    #[error( "the matrix is a reflection, its determinant is negative" )]
+    Reflection,
+    #[error( "the matrix is singular, it maps onto a lower dimensional space" )]
+    Singular,
+    #[error( "the matrix is not orthogonal, its columns are not orthonormal" )]
+    NotOrthogonal
+}
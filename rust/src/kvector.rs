@@ -0,0 +1,1167 @@
+// Copyright 2024 Bewusstsein Labs
+
+use std::{
+    fmt::Debug,
+    ops::{ Neg, Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign }
+};
+use num::traits::{ Num, Float };
+
+use linear_algebra::{
+    ops::Magnitude,
+    vector::Vector
+};
+
+use crate::{
+    ops::{
+        ExteriorProduct,
+        RegressiveProduct,
+        LeftContraction,
+        RightContraction,
+        HestenesInnerProduct,
+        FatDot,
+        ScalarProduct,
+        Reverse,
+        GradeInvolution,
+        CliffordConjugate,
+        Dual,
+        Undual,
+        HodgeStar
+    },
+    blade::{
+        self,
+        binomial
+    }
+};
+
+/// A k-vector type of generic element, size and grade.
+///
+/// Stores one component per basis blade of grade GRADE, C(DIM, GRADE) in total, ordered lexicographically,
+/// e.g. xy, xz, xw, yz, yw, zw for a bivector in 4D.
+///
+/// K-vectors carry no metric: their geometric and inner products, duals and Hodge star are those of the
+/// Euclidean metric. Use a [`Multivector`](crate::multivector::Multivector) with a [`Metric`](crate::metric::Metric)
+/// for any other signature.
+///
+#[derive( Clone, Copy, Debug )]
+pub struct KVector<T, const DIM: usize, const GRADE: usize>( [ T; binomial( DIM, GRADE ) ] )
+where
+    T: 'static + Default + Copy + Debug,
+    [(); binomial( DIM, GRADE )]:;
+
+impl<T, const DIM: usize, const GRADE: usize> KVector<T, DIM, GRADE>
+where
+    T: 'static + Copy + Default + Debug,
+    [(); binomial( DIM, GRADE )]:
+{
+    /// Creates a new const [`KVector`].
+    ///
+    pub const fn new_const( src: [T; binomial( DIM, GRADE )] ) -> Self {
+        Self ( src )
+    }
+
+    /// Creates a new [`KVector`].
+    ///
+    pub fn new( src: [T; binomial( DIM, GRADE )] ) -> Self {
+        Self ( src )
+    }
+
+    /// Creates a new zero filled [`KVector`].
+    ///
+    pub fn zero() -> Self
+    where
+        T: Num
+    {
+        Self ( [T::zero(); binomial( DIM, GRADE )] )
+    }
+
+    /// Returns an iterator over the elements of the [`KVector`].
+    ///
+    /// The iterator yields references to the elements of the [`KVector`] in order.
+    ///
+    pub fn iter( &self ) -> impl Iterator<Item = &T> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the [`KVector`].
+    ///
+    /// The iterator yields mutable references to the elements of the [`KVector`] in order.
+    ///
+    pub fn iter_mut( &mut self ) -> impl Iterator<Item = &mut T> {
+        self.0.iter_mut()
+    }
+
+    /// Returns the grade of the [`KVector`].
+    ///
+    pub const fn grade( &self ) -> usize {
+        GRADE
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Deref for KVector<T, DIM, GRADE>
+where
+    T: 'static + Copy + Default + Debug,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Target = [T; binomial( DIM, GRADE )];
+
+    fn deref( &self ) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> DerefMut for KVector<T, DIM, GRADE>
+where
+    T: 'static + Copy + Default + Debug,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn deref_mut( &mut self ) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Index<usize> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Copy + Default + Debug,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = T;
+
+    fn index( &self, index: usize ) -> &Self::Output {
+        &self.0[ index ]
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> IndexMut<usize> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Copy + Default + Debug,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn index_mut( &mut self, index: usize ) -> &mut Self::Output {
+        &mut self.0[ index ]
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Default for KVector<T, DIM, GRADE>
+where
+    T: 'static + Copy + Default + Debug,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn default() -> Self {
+        Self ( [T::default(); binomial( DIM, GRADE )] )
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> From<[T; binomial( DIM, GRADE )]> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Copy + Default + Debug,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn from( src: [T; binomial( DIM, GRADE )] ) -> Self {
+        Self ( src )
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> PartialEq for KVector<T, DIM, GRADE>
+where
+    T: 'static + Copy + Default + Debug + PartialEq,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn eq( &self, other: &Self ) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Neg for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Neg<Output = T>,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Self;
+
+    fn neg( mut self ) -> Self::Output {
+        self.iter_mut()
+            .for_each( |a| *a = -*a );
+        self
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Add for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Add<Output = T>,
+    Self: Clone,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Self;
+
+    fn add( mut self, other: Self ) -> Self::Output {
+        self.iter_mut().zip( other.iter() )
+            .for_each( |( a, &b )| *a = *a + b );
+        self
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Sub for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Sub<Output = T>,
+    Self: Clone,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Self;
+
+    fn sub( mut self, other: Self ) -> Self::Output {
+        self.iter_mut().zip( other.iter() )
+            .for_each( |( a, &b )| *a = *a - b );
+        self
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Add<T> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Add<Output = T>,
+    Self: Clone,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Self;
+
+    fn add( mut self, scalar: T ) -> Self::Output {
+        self.iter_mut()
+            .for_each( |a| *a = *a + scalar );
+        self
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Sub<T> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Sub<Output = T>,
+    Self: Clone,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Self;
+
+    fn sub( mut self, scalar: T ) -> Self::Output {
+        self.iter_mut()
+            .for_each( |a| *a = *a - scalar );
+        self
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Mul<T> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Mul<Output = T>,
+    Self: Clone,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Self;
+
+    fn mul( mut self, scalar: T ) -> Self::Output {
+        self.iter_mut()
+            .for_each( |a| *a = *a * scalar );
+        self
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Div<T> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Div<Output = T>,
+    Self: Clone,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Self;
+
+    fn div( mut self, scalar: T ) -> Self::Output {
+        self.iter_mut()
+            .for_each( |a| *a = *a / scalar );
+        self
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> AddAssign for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + AddAssign,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn add_assign( &mut self, other: Self ) {
+        self.iter_mut().zip( other.iter() )
+            .for_each( |( a, &b )| *a += b );
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> SubAssign for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + SubAssign,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn sub_assign( &mut self, other: Self ) {
+        self.iter_mut().zip( other.iter() )
+            .for_each( |( a, &b )| *a -= b );
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> AddAssign<T> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + AddAssign,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn add_assign( &mut self, scalar: T ) {
+        self.iter_mut()
+            .for_each( |a| *a += scalar );
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> SubAssign<T> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + SubAssign,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn sub_assign( &mut self, scalar: T ) {
+        self.iter_mut()
+            .for_each( |a| *a -= scalar );
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> MulAssign<T> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + MulAssign,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn mul_assign( &mut self, scalar: T ) {
+        self.iter_mut()
+            .for_each( |a| *a *= scalar );
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> DivAssign<T> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + DivAssign,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn div_assign( &mut self, scalar: T ) {
+        self.iter_mut()
+            .for_each( |a| *a /= scalar );
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Add<T> for &KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Add<Output = T>,
+    Self: Clone,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = KVector<T, DIM, GRADE>;
+
+    fn add( self, scalar: T ) -> Self::Output {
+        let mut result = KVector::<T, DIM, GRADE>::default();
+        self.iter().zip( result.iter_mut() )
+            .for_each( |( &a, c )| *c = a + scalar );
+        result
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Sub<T> for &KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Sub<Output = T>,
+    Self: Clone,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = KVector<T, DIM, GRADE>;
+
+    fn sub( self, scalar: T ) -> Self::Output {
+        let mut result = KVector::<T, DIM, GRADE>::default();
+        self.iter().zip( result.iter_mut() )
+            .for_each( |( &a, c )| *c = a - scalar );
+        result
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Mul<T> for &KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Mul<Output = T>,
+    Self: Clone,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = KVector<T, DIM, GRADE>;
+
+    fn mul( self, scalar: T ) -> Self::Output {
+        let mut result = KVector::<T, DIM, GRADE>::default();
+        self.iter().zip( result.iter_mut() )
+            .for_each( |( &a, c )| *c = a * scalar );
+        result
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Div<T> for &KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Div<Output = T>,
+    Self: Clone,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = KVector<T, DIM, GRADE>;
+
+    fn div( self, scalar: T ) -> Self::Output {
+        let mut result = KVector::<T, DIM, GRADE>::default();
+        self.iter().zip( result.iter_mut() )
+            .for_each( |( &a, c )| *c = a / scalar );
+        result
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> AddAssign<T> for &mut KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + AddAssign,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn add_assign( &mut self, scalar: T ) {
+        self.iter_mut()
+            .for_each( |a| *a += scalar );
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> SubAssign<T> for &mut KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + SubAssign,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn sub_assign( &mut self, scalar: T ) {
+        self.iter_mut()
+            .for_each( |a| *a -= scalar );
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> MulAssign<T> for &mut KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + MulAssign,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn mul_assign( &mut self, scalar: T ) {
+        self.iter_mut()
+            .for_each( |a| *a *= scalar );
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> DivAssign<T> for &mut KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + DivAssign,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn div_assign( &mut self, scalar: T ) {
+        self.iter_mut()
+            .for_each( |a| *a /= scalar );
+    }
+}
+
+
+impl<T, const DIM: usize> From<Vector<T, DIM>> for KVector<T, DIM, 1>
+where
+    T: 'static + Copy + Default + Debug,
+    [(); binomial( DIM, 1 )]:
+{
+    fn from( src: Vector<T, DIM> ) -> Self {
+        let mut res = Self::default();
+        res.iter_mut().zip( src.iter() )
+            .for_each( |( a, &b )| *a = b );
+        res
+    }
+}
+
+impl<T, const DIM: usize> From<KVector<T, DIM, 1>> for Vector<T, DIM>
+where
+    T: 'static + Copy + Default + Debug,
+    [(); binomial( DIM, 1 )]:
+{
+    fn from( src: KVector<T, DIM, 1> ) -> Self {
+        let mut res = Self::default();
+        res.iter_mut().zip( src.iter() )
+            .for_each( |( a, &b )| *a = b );
+        res
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Magnitude for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Copy + Debug + Div<Output = T> + Num + Float,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = T;
+
+    fn magnitude( &self ) -> Self::Output {
+        self.0.iter().fold( T::zero(), |acc, &x| acc + x * x ).sqrt()
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> Reverse for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Self;
+
+    fn reverse( self ) -> Self::Output {
+        if blade::reverse_negates( GRADE ) {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> GradeInvolution for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Self;
+
+    fn grade_involution( self ) -> Self::Output {
+        if blade::involution_negates( GRADE ) {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize> CliffordConjugate for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Self;
+
+    fn clifford_conjugate( self ) -> Self::Output {
+        if blade::conjugate_negates( GRADE ) {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+/// KVector<T, DIM, LHS> ∧ KVector<T, DIM, RHS>
+///
+/// Every pair of basis blades sharing no basis vector contributes ±e_(A ∪ B), the sign coming from
+/// reordering the basis vectors into canonical order. Vanishes when LHS + RHS exceeds DIM.
+///
+impl<T, const DIM: usize, const LHS: usize, const RHS: usize> ExteriorProduct<KVector<T, DIM, RHS>> for KVector<T, DIM, LHS>
+where
+    T: 'static + Default + Debug + Copy + Num,
+    [(); binomial( DIM, LHS )]:,
+    [(); binomial( DIM, RHS )]:,
+    [(); binomial( DIM, LHS + RHS )]:
+{
+    type Output = KVector<T, DIM, { LHS + RHS }>;
+
+    fn exterior_product( self, rhs: KVector<T, DIM, RHS> ) -> Self::Output {
+        let mut res = KVector::<T, DIM, { LHS + RHS }>::zero();
+        for ( i, &lhs ) in self.iter().enumerate() {
+            let a = blade::blade_mask( DIM, LHS, i );
+            for ( j, &other ) in rhs.iter().enumerate() {
+                let b = blade::blade_mask( DIM, RHS, j );
+                if a & b != 0 {
+                    continue;
+                }
+                let k = blade::blade_index( DIM, a | b );
+                if blade::reorder_negates( a, b ) {
+                    res[ k ] = res[ k ] - lhs * other;
+                } else {
+                    res[ k ] = res[ k ] + lhs * other;
+                }
+            }
+        }
+        res
+    }
+}
+
+/// Vector<T, DIM> ∧ KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize> ExteriorProduct<KVector<T, DIM, GRADE>> for Vector<T, DIM>
+where
+    T: 'static + Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, 1 + GRADE )]:
+{
+    type Output = KVector<T, DIM, { 1 + GRADE }>;
+
+    fn exterior_product( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        KVector::<T, DIM, 1>::from( self ).exterior_product( rhs )
+    }
+}
+
+/// KVector<T, DIM, GRADE> ∧ Vector<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize> ExteriorProduct<Vector<T, DIM>> for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, GRADE + 1 )]:
+{
+    type Output = KVector<T, DIM, { GRADE + 1 }>;
+
+    fn exterior_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        self.exterior_product( KVector::<T, DIM, 1>::from( rhs ) )
+    }
+}
+
+/// KVector<T, DIM, LHS> ∨ KVector<T, DIM, RHS>
+///
+/// Every pair of basis blades spanning the whole space contributes ±e_(A ∩ B), see [`RegressiveProduct`]
+/// for the sign convention.
+///
+impl<T, const DIM: usize, const LHS: usize, const RHS: usize> RegressiveProduct<KVector<T, DIM, RHS>> for KVector<T, DIM, LHS>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, LHS )]:,
+    [(); binomial( DIM, RHS )]:,
+    [(); binomial( DIM, usize::saturating_sub( LHS + RHS, DIM ) )]:
+{
+    type Output = KVector<T, DIM, { usize::saturating_sub( LHS + RHS, DIM ) }>;
+
+    fn regressive_product( self, rhs: KVector<T, DIM, RHS> ) -> Self::Output {
+        let mut res = Self::Output::default();
+        for i in 0..binomial( DIM, LHS ) {
+            let a = blade::blade_mask( DIM, LHS, i );
+            for j in 0..binomial( DIM, RHS ) {
+                let b = blade::blade_mask( DIM, RHS, j );
+                if a | b != blade::pseudoscalar_mask( DIM ) {
+                    continue;
+                }
+                let k = blade::blade_index( DIM, a & b );
+                if blade::regressive_negates( DIM, a, b ) {
+                    res[ k ] = res[ k ] - self[ i ] * rhs[ j ];
+                } else {
+                    res[ k ] = res[ k ] + self[ i ] * rhs[ j ];
+                }
+            }
+        }
+        res
+    }
+}
+
+/// Vector<T, DIM> ∨ KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize> RegressiveProduct<KVector<T, DIM, GRADE>> for Vector<T, DIM>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, usize::saturating_sub( 1 + GRADE, DIM ) )]:
+{
+    type Output = KVector<T, DIM, { usize::saturating_sub( 1 + GRADE, DIM ) }>;
+
+    fn regressive_product( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        KVector::<T, DIM, 1>::from( self ).regressive_product( rhs )
+    }
+}
+
+/// KVector<T, DIM, GRADE> ∨ Vector<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize> RegressiveProduct<Vector<T, DIM>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, usize::saturating_sub( GRADE + 1, DIM ) )]:
+{
+    type Output = KVector<T, DIM, { usize::saturating_sub( GRADE + 1, DIM ) }>;
+
+    fn regressive_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        self.regressive_product( KVector::<T, DIM, 1>::from( rhs ) )
+    }
+}
+
+/// Returns the sum of the products of the basis blades of `lhs` and `rhs` for which `keep( lhs blade, rhs blade )`
+/// holds and whose product has grade OUT.
+///
+fn contraction<T, const DIM: usize, const LHS: usize, const RHS: usize, const OUT: usize>( lhs: KVector<T, DIM, LHS>, rhs: KVector<T, DIM, RHS>, keep: impl Fn( usize, usize ) -> bool ) -> KVector<T, DIM, OUT>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, LHS )]:,
+    [(); binomial( DIM, RHS )]:,
+    [(); binomial( DIM, OUT )]:
+{
+    let mut res = KVector::<T, DIM, OUT>::zero();
+    for ( i, &x ) in lhs.iter().enumerate() {
+        let a = blade::blade_mask( DIM, LHS, i );
+        for ( j, &y ) in rhs.iter().enumerate() {
+            let b = blade::blade_mask( DIM, RHS, j );
+            if !keep( a, b ) || blade::grade( a ^ b ) != OUT {
+                continue;
+            }
+            let k = blade::blade_index( DIM, a ^ b );
+            if blade::reorder_negates( a, b ) {
+                res[ k ] = res[ k ] - x * y;
+            } else {
+                res[ k ] = res[ k ] + x * y;
+            }
+        }
+    }
+    res
+}
+
+/// KVector<T, DIM, LHS> ⌋ KVector<T, DIM, RHS>
+///
+impl<T, const DIM: usize, const LHS: usize, const RHS: usize> LeftContraction<KVector<T, DIM, RHS>> for KVector<T, DIM, LHS>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, LHS )]:,
+    [(); binomial( DIM, RHS )]:,
+    [(); binomial( DIM, usize::saturating_sub( RHS, LHS ) )]:
+{
+    type Output = KVector<T, DIM, { usize::saturating_sub( RHS, LHS ) }>;
+
+    fn left_contraction( self, rhs: KVector<T, DIM, RHS> ) -> Self::Output {
+        contraction( self, rhs, |a, b| a & b == a )
+    }
+}
+
+/// KVector<T, DIM, LHS> ⌊ KVector<T, DIM, RHS>
+///
+impl<T, const DIM: usize, const LHS: usize, const RHS: usize> RightContraction<KVector<T, DIM, RHS>> for KVector<T, DIM, LHS>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, LHS )]:,
+    [(); binomial( DIM, RHS )]:,
+    [(); binomial( DIM, usize::saturating_sub( LHS, RHS ) )]:
+{
+    type Output = KVector<T, DIM, { usize::saturating_sub( LHS, RHS ) }>;
+
+    fn right_contraction( self, rhs: KVector<T, DIM, RHS> ) -> Self::Output {
+        contraction( self, rhs, |a, b| a & b == b )
+    }
+}
+
+/// KVector<T, DIM, LHS> · KVector<T, DIM, RHS>
+///
+impl<T, const DIM: usize, const LHS: usize, const RHS: usize> HestenesInnerProduct<KVector<T, DIM, RHS>> for KVector<T, DIM, LHS>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, LHS )]:,
+    [(); binomial( DIM, RHS )]:,
+    [(); binomial( DIM, usize::abs_diff( LHS, RHS ) )]:
+{
+    type Output = KVector<T, DIM, { usize::abs_diff( LHS, RHS ) }>;
+
+    fn hestenes_inner_product( self, rhs: KVector<T, DIM, RHS> ) -> Self::Output {
+        contraction( self, rhs, |a, b| LHS != 0 && RHS != 0 && ( a & b == a || a & b == b ) )
+    }
+}
+
+/// KVector<T, DIM, LHS> • KVector<T, DIM, RHS>
+///
+impl<T, const DIM: usize, const LHS: usize, const RHS: usize> FatDot<KVector<T, DIM, RHS>> for KVector<T, DIM, LHS>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, LHS )]:,
+    [(); binomial( DIM, RHS )]:,
+    [(); binomial( DIM, usize::abs_diff( LHS, RHS ) )]:
+{
+    type Output = KVector<T, DIM, { usize::abs_diff( LHS, RHS ) }>;
+
+    fn fat_dot( self, rhs: KVector<T, DIM, RHS> ) -> Self::Output {
+        contraction( self, rhs, |a, b| a & b == a || a & b == b )
+    }
+}
+
+/// KVector<T, DIM, LHS> * KVector<T, DIM, RHS>
+///
+impl<T, const DIM: usize, const LHS: usize, const RHS: usize> ScalarProduct<KVector<T, DIM, RHS>> for KVector<T, DIM, LHS>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, LHS )]:,
+    [(); binomial( DIM, RHS )]:,
+    [(); binomial( DIM, 0 )]:
+{
+    type Output = T;
+
+    fn scalar_product( self, rhs: KVector<T, DIM, RHS> ) -> Self::Output {
+        contraction::<T, DIM, LHS, RHS, 0>( self, rhs, |a, b| a == b )[ 0 ]
+    }
+}
+
+/// Vector<T, DIM> ⌋ KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize> LeftContraction<KVector<T, DIM, GRADE>> for Vector<T, DIM>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, usize::saturating_sub( GRADE, 1 ) )]:
+{
+    type Output = KVector<T, DIM, { usize::saturating_sub( GRADE, 1 ) }>;
+
+    fn left_contraction( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        KVector::<T, DIM, 1>::from( self ).left_contraction( rhs )
+    }
+}
+
+/// KVector<T, DIM, GRADE> ⌋ Vector<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize> LeftContraction<Vector<T, DIM>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, usize::saturating_sub( 1, GRADE ) )]:
+{
+    type Output = KVector<T, DIM, { usize::saturating_sub( 1, GRADE ) }>;
+
+    fn left_contraction( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        self.left_contraction( KVector::<T, DIM, 1>::from( rhs ) )
+    }
+}
+
+/// Vector<T, DIM> ⌊ KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize> RightContraction<KVector<T, DIM, GRADE>> for Vector<T, DIM>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, usize::saturating_sub( 1, GRADE ) )]:
+{
+    type Output = KVector<T, DIM, { usize::saturating_sub( 1, GRADE ) }>;
+
+    fn right_contraction( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        KVector::<T, DIM, 1>::from( self ).right_contraction( rhs )
+    }
+}
+
+/// KVector<T, DIM, GRADE> ⌊ Vector<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize> RightContraction<Vector<T, DIM>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, usize::saturating_sub( GRADE, 1 ) )]:
+{
+    type Output = KVector<T, DIM, { usize::saturating_sub( GRADE, 1 ) }>;
+
+    fn right_contraction( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        self.right_contraction( KVector::<T, DIM, 1>::from( rhs ) )
+    }
+}
+
+/// Vector<T, DIM> · KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize> HestenesInnerProduct<KVector<T, DIM, GRADE>> for Vector<T, DIM>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, usize::abs_diff( 1, GRADE ) )]:
+{
+    type Output = KVector<T, DIM, { usize::abs_diff( 1, GRADE ) }>;
+
+    fn hestenes_inner_product( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        KVector::<T, DIM, 1>::from( self ).hestenes_inner_product( rhs )
+    }
+}
+
+/// KVector<T, DIM, GRADE> · Vector<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize> HestenesInnerProduct<Vector<T, DIM>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, usize::abs_diff( GRADE, 1 ) )]:
+{
+    type Output = KVector<T, DIM, { usize::abs_diff( GRADE, 1 ) }>;
+
+    fn hestenes_inner_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        self.hestenes_inner_product( KVector::<T, DIM, 1>::from( rhs ) )
+    }
+}
+
+/// Vector<T, DIM> • KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize> FatDot<KVector<T, DIM, GRADE>> for Vector<T, DIM>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, usize::abs_diff( 1, GRADE ) )]:
+{
+    type Output = KVector<T, DIM, { usize::abs_diff( 1, GRADE ) }>;
+
+    fn fat_dot( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        KVector::<T, DIM, 1>::from( self ).fat_dot( rhs )
+    }
+}
+
+/// KVector<T, DIM, GRADE> • Vector<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize> FatDot<Vector<T, DIM>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, usize::abs_diff( GRADE, 1 ) )]:
+{
+    type Output = KVector<T, DIM, { usize::abs_diff( GRADE, 1 ) }>;
+
+    fn fat_dot( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        self.fat_dot( KVector::<T, DIM, 1>::from( rhs ) )
+    }
+}
+
+/// Vector<T, DIM> * KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize> ScalarProduct<KVector<T, DIM, GRADE>> for Vector<T, DIM>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, 0 )]:
+{
+    type Output = T;
+
+    fn scalar_product( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        KVector::<T, DIM, 1>::from( self ).scalar_product( rhs )
+    }
+}
+
+/// KVector<T, DIM, GRADE> * Vector<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize> ScalarProduct<Vector<T, DIM>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Debug + Copy + Num,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, 0 )]:
+{
+    type Output = T;
+
+    fn scalar_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        self.scalar_product( KVector::<T, DIM, 1>::from( rhs ) )
+    }
+}
+
+/// Maps every basis blade of `src` to its complement, negating the blades for which `negates( blade )`.
+///
+fn complement<T, const DIM: usize, const GRADE: usize>( src: KVector<T, DIM, GRADE>, negates: impl Fn( usize ) -> bool ) -> KVector<T, DIM, { DIM - GRADE }>
+where
+    T: 'static + Default + Debug + Copy + Neg<Output = T>,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, DIM - GRADE )]:
+{
+    let mut res = KVector::<T, DIM, { DIM - GRADE }>::default();
+    for i in 0..binomial( DIM, GRADE ) {
+        let a = blade::blade_mask( DIM, GRADE, i );
+        let k = blade::blade_index( DIM, blade::pseudoscalar_mask( DIM ) ^ a );
+        res[ k ] = if negates( a ) { -src[ i ] } else { src[ i ] };
+    }
+    res
+}
+
+/// (KVector<T, DIM, GRADE>)*
+///
+impl<T, const DIM: usize, const GRADE: usize> Dual for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Debug + Copy + Neg<Output = T>,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, DIM - GRADE )]:
+{
+    type Output = KVector<T, DIM, { DIM - GRADE }>;
+
+    fn dual( self ) -> Self::Output {
+        complement( self, |a| blade::dual_negates( DIM, a ) )
+    }
+}
+
+/// (Vector<T, DIM>)*
+///
+impl<T, const DIM: usize> Dual for Vector<T, DIM>
+where
+    T: 'static + Default + Debug + Copy + Neg<Output = T>,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, DIM - 1 )]:
+{
+    type Output = KVector<T, DIM, { DIM - 1 }>;
+
+    fn dual( self ) -> Self::Output {
+        KVector::<T, DIM, 1>::from( self ).dual()
+    }
+}
+
+/// undual( KVector<T, DIM, GRADE> )
+///
+impl<T, const DIM: usize, const GRADE: usize> Undual for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Debug + Copy + Neg<Output = T>,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, DIM - GRADE )]:
+{
+    type Output = KVector<T, DIM, { DIM - GRADE }>;
+
+    fn undual( self ) -> Self::Output {
+        complement( self, |a| blade::undual_negates( DIM, a ) )
+    }
+}
+
+/// undual( Vector<T, DIM> )
+///
+impl<T, const DIM: usize> Undual for Vector<T, DIM>
+where
+    T: 'static + Default + Debug + Copy + Neg<Output = T>,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, DIM - 1 )]:
+{
+    type Output = KVector<T, DIM, { DIM - 1 }>;
+
+    fn undual( self ) -> Self::Output {
+        KVector::<T, DIM, 1>::from( self ).undual()
+    }
+}
+
+/// ⋆KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize> HodgeStar for KVector<T, DIM, GRADE>
+where
+    T: 'static + Default + Debug + Copy + Neg<Output = T>,
+    [(); binomial( DIM, GRADE )]:,
+    [(); binomial( DIM, DIM - GRADE )]:
+{
+    type Output = KVector<T, DIM, { DIM - GRADE }>;
+
+    fn hodge_star( self ) -> Self::Output {
+        complement( self, |a| blade::hodge_negates( DIM, a ) )
+    }
+}
+
+/// ⋆Vector<T, DIM>
+///
+impl<T, const DIM: usize> HodgeStar for Vector<T, DIM>
+where
+    T: 'static + Default + Debug + Copy + Neg<Output = T>,
+    [(); binomial( DIM, 1 )]:,
+    [(); binomial( DIM, DIM - 1 )]:
+{
+    type Output = KVector<T, DIM, { DIM - 1 }>;
+
+    fn hodge_star( self ) -> Self::Output {
+        KVector::<T, DIM, 1>::from( self ).hodge_star()
+    }
+}
+
+pub type KVector2<T, const GRADE: usize> = KVector<T, 2, GRADE>;
+pub type KVector3<T, const GRADE: usize> = KVector<T, 3, GRADE>;
+pub type KVector4<T, const GRADE: usize> = KVector<T, 4, GRADE>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linear_algebra::vector::Vector;
+    use crate::{
+        ops::{
+            LeftContraction,
+            RightContraction,
+            ScalarProduct,
+            Dual,
+            Undual,
+            HodgeStar
+        },
+        scalar::Scalar,
+        pseudoscalar::Pseudoscalar,
+        bivector::BiVector
+    };
+
+    #[test]
+    fn left_contraction_vector_bivector_3() {
+        let x = Vector::<f64, 3>::new([ 1.0, 0.0, 0.0 ]);
+        let y = Vector::<f64, 3>::new([ 0.0, 1.0, 0.0 ]);
+        let xy = BiVector::<f64, 3>::new([ 1.0, 0.0, 0.0 ]);
+
+        assert_eq!( x.left_contraction( xy ), KVector::<f64, 3, 1>::new([ 0.0, 1.0, 0.0 ]) ); // x xy = y
+        assert_eq!( y.left_contraction( xy ), KVector::<f64, 3, 1>::new([ -1.0, 0.0, 0.0 ]) ); // y xy = -x
+        assert_eq!( xy.left_contraction( x ), Scalar::<f64, 3>::new([ 0.0 ]) );
+    }
+
+    #[test]
+    fn right_contraction_bivector_vector_3() {
+        let x = Vector::<f64, 3>::new([ 1.0, 0.0, 0.0 ]);
+        let y = Vector::<f64, 3>::new([ 0.0, 1.0, 0.0 ]);
+        let xy = BiVector::<f64, 3>::new([ 1.0, 0.0, 0.0 ]);
+
+        assert_eq!( xy.right_contraction( y ), KVector::<f64, 3, 1>::new([ 1.0, 0.0, 0.0 ]) ); // xy y = x
+        assert_eq!( xy.right_contraction( x ), KVector::<f64, 3, 1>::new([ 0.0, -1.0, 0.0 ]) ); // xy x = -y
+    }
+
+    #[test]
+    fn scalar_product_bivector_3() {
+        let lhs = BiVector::<f64, 3>::new([ 8.0, 3.0, 1.0 ]);
+        let rhs = BiVector::<f64, 3>::new([ 4.0, 5.0, 6.0 ]);
+
+        assert_eq!( lhs.scalar_product( rhs ), -( lhs[ 0 ] * rhs[ 0 ] + lhs[ 1 ] * rhs[ 1 ] + lhs[ 2 ] * rhs[ 2 ] ) );
+    }
+
+    #[test]
+    fn hodge_star_3() {
+        let v = Vector::<f64, 3>::new([ 1.0, 2.0, 3.0 ]);
+        let b = BiVector::<f64, 3>::new([ 8.0, 3.0, 1.0 ]);
+
+        assert_eq!( b.hodge_star(), KVector::<f64, 3, 1>::new([ 1.0, -3.0, 8.0 ]) ); // ⋆xy = z, ⋆xz = -y, ⋆yz = x
+        assert_eq!( v.hodge_star(), BiVector::<f64, 3>::new([ 3.0, -2.0, 1.0 ]) );
+        assert_eq!( v.exterior_product( v.hodge_star() ), KVector::<f64, 3, 3>::new([ 14.0 ]) ); // v ∧ ⋆v = |v|² I
+    }
+
+    #[test]
+    fn dual_3() {
+        let v = Vector::<f64, 3>::new([ 1.0, 2.0, 3.0 ]);
+
+        assert_eq!( v.dual(), BiVector::<f64, 3>::new([ -3.0, 2.0, -1.0 ]) ); // x I⁻¹ = -yz
+        assert_eq!( v.dual().undual(), KVector::<f64, 3, 1>::from( v ) );
+    }
+
+    #[test]
+    fn dual_undual_4() {
+        let b = BiVector::<f64, 4>::new([ 1.0, 2.0, 3.0, 4.0, 5.0, 6.0 ]);
+
+        assert_eq!( b.dual().undual(), b );
+        assert_eq!( b.hodge_star().hodge_star(), b ); // ⋆⋆ = (-1)^(k (DIM - k)) = 1
+    }
+
+    #[test]
+    fn regressive_product_3() {
+        let x = Vector::<f64, 3>::new([ 1.0, 0.0, 0.0 ]);
+        let xy = BiVector::<f64, 3>::new([ 1.0, 0.0, 0.0 ]);
+        let yz = BiVector::<f64, 3>::new([ 0.0, 0.0, 1.0 ]);
+
+        assert_eq!( xy.regressive_product( yz ), KVector::<f64, 3, 1>::new([ 0.0, -1.0, 0.0 ]) ); // the planes meet in the y axis
+        assert_eq!( x.regressive_product( yz ), Scalar::<f64, 3>::new([ 1.0 ]) ); // x ∧ yz = I
+        assert_eq!( Pseudoscalar::<f64, 3>::unit().regressive_product( xy ), xy );
+    }
+
+    #[test]
+    fn regressive_product_plane_line_4() {
+        // Points in homogeneous coordinates, w last
+        let o = Vector::<f64, 4>::new([ 0.0, 0.0, 0.0, 1.0 ]);
+        let px = Vector::<f64, 4>::new([ 1.0, 0.0, 0.0, 1.0 ]);
+        let py = Vector::<f64, 4>::new([ 0.0, 1.0, 0.0, 1.0 ]);
+        let p = Vector::<f64, 4>::new([ 1.0, 2.0, 3.0, 1.0 ]);
+        let q = Vector::<f64, 4>::new([ 3.0, 4.0, -1.0, 1.0 ]);
+
+        let plane = o.exterior_product( px ).exterior_product( py ); // z = 0
+        let line = p.exterior_product( q );
+
+        // The line crosses z = 0 at ( 2.5, 3.5, 0 )
+        assert_eq!( plane.regressive_product( line ), KVector::<f64, 4, 1>::new([ 10.0, 14.0, 0.0, 4.0 ]) );
+    }
+
+    #[test]
+    fn regressive_product_plane_plane_4() {
+        let o = Vector::<f64, 4>::new([ 0.0, 0.0, 0.0, 1.0 ]);
+        let x = Vector::<f64, 4>::new([ 1.0, 0.0, 0.0, 0.0 ]);
+        let px = Vector::<f64, 4>::new([ 1.0, 0.0, 0.0, 1.0 ]);
+        let py = Vector::<f64, 4>::new([ 0.0, 1.0, 0.0, 1.0 ]);
+        let pz = Vector::<f64, 4>::new([ 0.0, 0.0, 1.0, 1.0 ]);
+
+        let z0 = o.exterior_product( px ).exterior_product( py );
+        let y0 = o.exterior_product( px ).exterior_product( pz );
+
+        // The planes z = 0 and y = 0 meet in the x axis
+        assert_eq!( z0.regressive_product( y0 ), o.exterior_product( x ) );
+    }
+}
@@ -4,22 +4,27 @@
 #![feature(adt_const_params)]
 #![feature(generic_const_exprs)]
 
+pub mod blade;
+pub mod metric;
 pub mod traits;
 pub mod ops;
+pub mod kvector;
+pub mod scalar;
 pub mod vector;
 pub mod bivector;
 pub mod trivector;
-//pub mod multivector;
+pub mod quadvector;
+pub mod pseudoscalar;
+pub mod multivector;
 pub mod rotor;
+pub mod rotor2;
+pub mod rotor3;
+pub mod rotor4;
+pub mod pga2;
+pub mod pga3;
+pub mod motor;
+pub mod error;
 
-use linear_algebra::{
-    ops::InnerProduct,
-    vector::Vector
-};
-use crate::{
-    ops::{
-        ExteriorProduct,
-        GeometricProduct
-    },
-    bivector::BiVector
-};
+mod matrix;
+#[cfg(test)]
+mod test_util;
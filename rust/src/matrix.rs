@@ -0,0 +1,160 @@
+// Copyright 2024 Bewusstsein Labs
+
+use num::traits::{ Num, Float };
+
+/// Returns the determinant of the square matrix `m` by Gaussian elimination with partial pivoting.
+///
+pub(crate) fn determinant<T, const N: usize>( mut m: [ [ T; N ]; N ] ) -> T
+where
+    T: Copy + Num + Float
+{
+    let mut res = T::one();
+    for col in 0..N {
+        let pivot = ( col..N ).fold( col, |best, row| if m[ row ][ col ].abs() > m[ best ][ col ].abs() { row } else { best } );
+        if m[ pivot ][ col ] == T::zero() {
+            return T::zero();
+        }
+        if pivot != col {
+            m.swap( pivot, col );
+            res = -res;
+        }
+        res = res * m[ col ][ col ];
+        for row in col + 1..N {
+            let ( pivot, factor ) = ( m[ col ], m[ row ][ col ] / m[ col ][ col ] );
+            for ( value, &x ) in m[ row ].iter_mut().zip( pivot.iter() ).skip( col ) {
+                *value = *value - factor * x;
+            }
+        }
+    }
+    res
+}
+
+/// Returns `true` if every entry of Mᵀ M for the square matrix `m` is within `epsilon` of the identity.
+///
+pub(crate) fn is_orthogonal<T, const N: usize>( m: [ [ T; N ]; N ], epsilon: T ) -> bool
+where
+    T: Copy + Num + Float
+{
+    ( 0..N ).all( |row| ( 0..N ).all( |col| {
+        let product = ( 0..N ).fold( T::zero(), |acc, k| acc + m[ k ][ row ] * m[ k ][ col ] );
+        ( product - if row == col { T::one() } else { T::zero() } ).abs() <= epsilon
+    } ) )
+}
+
+/// Returns the inverse of the square matrix `m` by Gauss-Jordan elimination with partial pivoting, or `None` if
+/// it is singular.
+///
+pub(crate) fn inverse<T, const N: usize>( mut m: [ [ T; N ]; N ] ) -> Option<[ [ T; N ]; N ]>
+where
+    T: Copy + Num + Float
+{
+    let mut res = [ [ T::zero(); N ]; N ];
+    for ( i, row ) in res.iter_mut().enumerate() {
+        row[ i ] = T::one();
+    }
+    for col in 0..N {
+        let pivot = ( col..N ).fold( col, |best, row| if m[ row ][ col ].abs() > m[ best ][ col ].abs() { row } else { best } );
+        if m[ pivot ][ col ] == T::zero() {
+            return None;
+        }
+        m.swap( pivot, col );
+        res.swap( pivot, col );
+        let scale = T::one() / m[ col ][ col ];
+        for k in 0..N {
+            m[ col ][ k ] = m[ col ][ k ] * scale;
+            res[ col ][ k ] = res[ col ][ k ] * scale;
+        }
+        for row in 0..N {
+            if row == col {
+                continue;
+            }
+            let factor = m[ row ][ col ];
+            for k in 0..N {
+                m[ row ][ k ] = m[ row ][ k ] - factor * m[ col ][ k ];
+                res[ row ][ k ] = res[ row ][ k ] - factor * res[ col ][ k ];
+            }
+        }
+    }
+    Some( res )
+}
+
+/// Returns the orthogonal factor Q of the polar decomposition M = Q P of the square matrix `m`, the orthogonal
+/// matrix closest to it in the Frobenius norm, or `None` if it is singular.
+///
+/// Newton's iteration X ← ( X + X⁻ᵀ ) / 2 converges quadratically and keeps the sign of the determinant, so a
+/// rotation stays a rotation.
+///
+pub(crate) fn polar<T, const N: usize>( m: [ [ T; N ]; N ] ) -> Option<[ [ T; N ]; N ]>
+where
+    T: Copy + Num + Float
+{
+    let half = T::one() / ( T::one() + T::one() );
+    let mut res = m;
+    for _ in 0..64 {
+        let inverse = inverse( res )?;
+        let mut difference = T::zero();
+        for row in 0..N {
+            for col in 0..N {
+                let next = ( res[ row ][ col ] + inverse[ col ][ row ] ) * half;
+                difference = difference + ( next - res[ row ][ col ] ) * ( next - res[ row ][ col ] );
+                res[ row ][ col ] = next;
+            }
+        }
+        // Quadratic convergence, a step of √ε leaves an error of about ε
+        if difference <= T::epsilon() {
+            break;
+        }
+    }
+    Some( res )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::EPSILON;
+
+    #[test]
+    fn determinant_3() {
+        let m = [ [ 2.0, 0.0, 1.0 ], [ 1.0, 3.0, 2.0 ], [ 1.0, 1.0, 2.0 ] ];
+
+        assert!( ( determinant( m ) - 6.0 ).abs() < EPSILON );
+        assert_eq!( determinant( [ [ 1.0, 2.0 ], [ 2.0, 4.0 ] ] ), 0.0 );
+    }
+
+    #[test]
+    fn is_orthogonal_2() {
+        let ( cos, sin ) = ( 0.6, 0.8 );
+
+        assert!( is_orthogonal( [ [ cos, -sin ], [ sin, cos ] ], EPSILON ) );
+        assert!( !is_orthogonal( [ [ 3.0 * cos, -sin ], [ 3.0 * sin, cos ] ], EPSILON ) );
+    }
+
+    #[test]
+    fn inverse_3() {
+        let m = [ [ 2.0, 0.0, 1.0 ], [ 1.0, 3.0, 2.0 ], [ 1.0, 1.0, 2.0 ] ];
+
+        let res = inverse( m ).unwrap();
+
+        for ( row, values ) in m.iter().enumerate() {
+            assert!( ( 0..3 ).all( |col| {
+                let product = ( 0..3 ).fold( 0.0, |acc, k| acc + values[ k ] * res[ k ][ col ] );
+                ( product - if row == col { 1.0 } else { 0.0 } ).abs() < EPSILON
+            } ) );
+        }
+        assert_eq!( inverse( [ [ 1.0, 2.0 ], [ 2.0, 4.0 ] ] ), None );
+    }
+
+    #[test]
+    fn polar_2() {
+        // A stretch along x followed by a rotation, Q is the rotation
+        let ( cos, sin ) = ( 0.6, 0.8 );
+        let m = [ [ 3.0 * cos, -sin ], [ 3.0 * sin, cos ] ];
+
+        let res = polar( m ).unwrap();
+
+        assert!( ( res[ 0 ][ 0 ] - cos ).abs() < EPSILON );
+        assert!( ( res[ 0 ][ 1 ] + sin ).abs() < EPSILON );
+        assert!( ( res[ 1 ][ 0 ] - sin ).abs() < EPSILON );
+        assert!( ( res[ 1 ][ 1 ] - cos ).abs() < EPSILON );
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2024 Bewusstsein Labs
+
+use std::fmt::Debug;
+
+use crate::blade;
+
+/// The metric of a Clifford algebra, i.e. what every basis vector squares to.
+///
+/// Only [`Multivector`](crate::multivector::Multivector) and [`Rotor`](crate::rotor::Rotor) take a metric.
+/// [`Vector`](linear_algebra::vector::Vector) comes from `linear_algebra` and cannot carry one, so it and the
+/// k-vector types stay Euclidean.
+///
+pub trait Metric: 'static + Copy + Default + Debug {
+    /// Returns the square of the basis vector e_`index`: 1, -1 or 0.
+    ///
+    fn square( index: usize ) -> i8;
+
+    /// Returns the factor of the product of the basis blades `a` and `b`: 1, -1 or 0.
+    ///
+    /// This is the reordering sign of the product times the squares of the basis vectors the blades share.
+    ///
+    fn product( a: usize, b: usize ) -> i8 {
+        let mut res = if blade::reorder_negates( a, b ) { -1 } else { 1 };
+        let mut common = a & b;
+        let mut index = 0;
+        while common != 0 {
+            if common & 1 != 0 {
+                res *= Self::square( index );
+            }
+            common >>= 1;
+            index += 1;
+        }
+        res
+    }
+
+    /// Returns `true` if the first `dim` basis vectors all square to 1.
+    ///
+    fn is_euclidean( dim: usize ) -> bool {
+        ( 0..dim ).all( |index| Self::square( index ) == 1 )
+    }
+
+    /// Returns `true` if one of the first `dim` basis vectors squares to 0, the pseudoscalar then has no inverse.
+    ///
+    fn is_degenerate( dim: usize ) -> bool {
+        ( 0..dim ).any( |index| Self::square( index ) == 0 )
+    }
+}
+
+/// The Euclidean metric, every basis vector squares to 1.
+///
+#[derive( Clone, Copy, Debug, Default, PartialEq, Eq )]
+pub struct Euclidean;
+
+impl Metric for Euclidean {
+    fn square( _index: usize ) -> i8 {
+        1
+    }
+}
+
+/// The metric of the Clifford algebra Cl(P, Q, R).
+///
+/// The first P basis vectors square to 1, the next Q to -1 and the last R to 0.
+///
+#[derive( Clone, Copy, Debug, Default, PartialEq, Eq )]
+pub struct Signature<const P: usize, const Q: usize, const R: usize>;
+
+impl<const P: usize, const Q: usize, const R: usize> Metric for Signature<P, Q, R> {
+    fn square( index: usize ) -> i8 {
+        if index < P {
+            1
+        } else if index < P + Q {
+            -1
+        } else {
+            0
+        }
+    }
+}
+
+/// 2D projective geometric algebra Cl(2, 0, 1), e0 is the last basis vector.
+///
+pub type Projective2 = Signature<2, 0, 1>;
+
+/// 3D projective geometric algebra Cl(3, 0, 1), e0 is the last basis vector.
+///
+pub type Projective3 = Signature<3, 0, 1>;
+
+/// 3D conformal geometric algebra Cl(4, 1, 0).
+///
+pub type Conformal3 = Signature<4, 1, 0>;
+
+/// Spacetime algebra Cl(1, 3, 0), time is the first basis vector.
+///
+pub type Spacetime = Signature<1, 3, 0>;
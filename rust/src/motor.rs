@@ -0,0 +1,284 @@
+// Copyright 2024 Bewusstsein Labs
+
+use std::fmt::Debug;
+use num::traits::{ Num, Float, NumCast };
+
+use linear_algebra::{
+    ops::Normalize,
+    vector::Vector
+};
+
+use crate::{
+    ops::{
+        ExteriorProduct,
+        Reverse,
+        Rotate
+    },
+    traits::{
+        ScalarComponent,
+        BiVectorComponent,
+        QuadVectorComponent
+    },
+    metric::Projective3,
+    bivector::BiVector,
+    quadvector::QuadVector,
+    rotor::{
+        Rotor,
+        Rotor3
+    },
+    pga3::{
+        Plane,
+        Line,
+        Point
+    }
+};
+
+/// A motor, a rigid body motion of 3D projective geometric algebra.
+///
+/// The even subalgebra of PGA3: a scalar, the bivector xy, xz, xw, yz, yw, zw and the pseudoscalar xyzw, with w = e0.
+/// It is a [`Rotor`] in the metric [`Projective3`], so it composes with `*`, `self * rhs` applying `rhs` first, and
+/// moves planes, lines and points by the sandwich M X M̃ through [`Rotate`].
+///
+pub type Motor<T> = Rotor<T, 4, Projective3>;
+
+/// Returns I B, I = xyzw, which maps the Euclidean part of the bivector `b` onto the ideal bivector of the same
+/// direction and drops its ideal part.
+///
+fn ideal_dual<T>( b: BiVector<T, 4> ) -> BiVector<T, 4>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    BiVector::new([ T::zero(), T::zero(), -b[ 3 ], T::zero(), b[ 1 ], -b[ 0 ] ])
+}
+
+impl<T> Motor<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Creates the [`Motor`] moving everything by `translation`, T = 1 + ½ ( t_x xw + t_y yw + t_z zw ).
+    ///
+    pub fn from_translation( translation: Vector<T, 3> ) -> Self {
+        let half = T::one() / ( T::one() + T::one() );
+        Motor::new( T::one(), BiVector::new([
+            T::zero(),
+            T::zero(),
+            translation[ 0 ] * half,
+            T::zero(),
+            translation[ 1 ] * half,
+            translation[ 2 ] * half
+        ]) )
+    }
+
+    /// Creates the screw [`Motor`] turning by `angle` radians about `axis`, counterclockwise looking down its direction,
+    /// while moving by `pitch` · `angle` along it.
+    ///
+    /// M = e^( -θ / 2 ( L̂ + p I L̂ ) ) with L̂ the normalized axis. A pitch of zero gives a pure rotation about the axis.
+    ///
+    pub fn from_screw( axis: Line<T>, pitch: T, angle: T ) -> Self {
+        let axis = BiVector::from( axis.normalize() );
+        let half = angle / ( T::one() + T::one() );
+        Line::from( ( axis + ideal_dual( axis ) * pitch ) * -half ).exp()
+    }
+
+    /// Returns the [`Motor`] scaled to M M̃ = 1.
+    ///
+    /// M M̃ = a + b I for any motor, so M / √( M M̃ ) = M ( 1 - b / ( 2a ) I ) / √a. Unlike [`Normalize::normalize`],
+    /// which only divides by √a, this also removes the drift that makes a motor shear.
+    ///
+    pub fn unitize( self ) -> Self {
+        let norm = self * self.reverse();
+        let ( a, b ) = ( *norm.scalar(), norm.quadvector()[ 0 ] );
+        let scale = T::one() / a.sqrt();
+        self * Motor::new_even( scale, BiVector::zero(), QuadVector::new([ -b / ( a + a ) * scale ]) )
+    }
+
+    /// Returns the logarithm of the unit [`Motor`], the [`Line`] bivector B with e^B = M, see [`Line::exp`].
+    ///
+    /// M = cos U + sin U / U · B for the dual number U = u + ε I, so u = atan2( |⟨M⟩₂ Euclidean|, ⟨M⟩₀ ) and the
+    /// pseudoscalar part -ε sin u fixes ε. A motor without rotation gives its translation bivector, M ≈ -1 has no
+    /// unique logarithm.
+    ///
+    pub fn log( &self ) -> Line<T> {
+        let ( s, b, q ) = ( *self.scalar(), *self.bivector(), self.quadvector()[ 0 ] );
+        let n = ( b[ 0 ] * b[ 0 ] + b[ 1 ] * b[ 1 ] + b[ 3 ] * b[ 3 ] ).sqrt();
+        let u = n.atan2( s );
+        let three = <T as NumCast>::from( 3 ).unwrap();
+        let fifteen = <T as NumCast>::from( 15 ).unwrap();
+        let ( g, h ) = if u < T::epsilon().sqrt().sqrt() {
+            // ( sin u - u cos u ) / sin³ u = 1/3 + 2/15 u² + O( u⁴ ) cancels badly for small u
+            let g = if n == T::zero() { T::one() } else { u / n };
+            ( g, -q * ( T::one() / three + ( T::one() + T::one() ) / fifteen * u * u ) )
+        } else {
+            ( u / n, -q * ( n - u * s ) / ( n * n * n ) )
+        };
+        Line::from( b * g + ideal_dual( b ) * h )
+    }
+}
+
+impl<T> Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Returns the exponential e^B of the [`Line`] bivector as a [`Motor`].
+    ///
+    /// B² = -u² + 2k I with u the length of the Euclidean part, so B behaves like i U with the dual number
+    /// U = u - k / u I and e^B = cos U + sin U / U · B. A line through the origin exponentiates like
+    /// [`BiVector::exp`], an ideal line to the translation 1 + B.
+    ///
+    pub fn exp( &self ) -> Motor<T> {
+        let b = BiVector::from( *self );
+        let u_squared = b[ 0 ] * b[ 0 ] + b[ 1 ] * b[ 1 ] + b[ 3 ] * b[ 3 ];
+        if u_squared == T::zero() {
+            return Motor::new( T::one(), b );
+        }
+        let k = b.exterior_product( b )[ 0 ] / ( T::one() + T::one() );
+        let u = u_squared.sqrt();
+        let ( c, s ) = ( u.cos(), u.sin() / u );
+        let t = k / u_squared * ( c - s );
+        Motor::new_even( c, b * s - ideal_dual( b ) * t, QuadVector::new([ k * s ]) )
+    }
+}
+
+/// The [`Motor`] rotating like the [`Rotor3`] about the origin.
+///
+impl<T> From<Rotor3<T>> for Motor<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: Rotor3<T> ) -> Self {
+        let b = src.bivector();
+        Motor::new( *src.scalar(), BiVector::new([ b[ 0 ], b[ 1 ], T::zero(), b[ 2 ], T::zero(), T::zero() ]) )
+    }
+}
+
+/// M Plane<T> M̃
+///
+impl<T> Rotate<Plane<T>> for Motor<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Plane<T>;
+
+    fn rotate( self, rhs: Plane<T> ) -> Self::Output {
+        Plane::from( self.rotate( *rhs ) )
+    }
+}
+
+/// M Line<T> M̃
+///
+impl<T> Rotate<Line<T>> for Motor<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Line<T>;
+
+    fn rotate( self, rhs: Line<T> ) -> Self::Output {
+        Line::from( self.rotate( *rhs ) )
+    }
+}
+
+/// M Point<T> M̃
+///
+impl<T> Rotate<Point<T>> for Motor<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Point<T>;
+
+    fn rotate( self, rhs: Point<T> ) -> Self::Output {
+        Point::from( self.rotate( *rhs ) )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ops::ScalarProduct,
+        test_util::{ EPSILON, assert_rotor, assert_position }
+    };
+
+    #[test]
+    fn translation() {
+        let motor = Motor::<f64>::from_translation( Vector::new([ 1.0, -2.0, 0.5 ]) );
+
+        assert_position( motor.rotate( Point::new( 1.0, 1.0, 1.0 ) ).position(), [ 2.0, -1.0, 1.5 ] );
+        // x = 1 moves to x = 2
+        let plane = motor.rotate( Plane::new( 1.0, 0.0, 0.0, -1.0 ) );
+        assert!( ( plane[ 3 ] / plane[ 0 ] + 2.0 ).abs() < EPSILON );
+        // The z axis moves to the line along z through ( 1, -2, 0 )
+        let line = motor.rotate( Line::new( Vector::new([ 0.0, 0.0, 1.0 ]), Vector::default() ) );
+        assert_eq!( line.direction(), Vector::new([ 0.0, 0.0, 1.0 ]) );
+        assert!( ( line.moment()[ 0 ] + 2.0 ).abs() < EPSILON );
+        assert!( ( line.moment()[ 1 ] + 1.0 ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn rotor3() {
+        let rotor = Rotor3::<f64>::from_axis_angle( Vector::new([ 1.0, 2.0, 0.5 ]), 1.1 );
+        let v = Vector::<f64, 3>::new([ 0.3, -1.2, 2.0 ]);
+
+        let res = Motor::from( rotor ).rotate( Point::new( v[ 0 ], v[ 1 ], v[ 2 ] ) );
+        let expected = rotor.rotate( v );
+
+        assert_position( res.position(), [ expected[ 0 ], expected[ 1 ], expected[ 2 ] ] );
+    }
+
+    #[test]
+    fn compose() {
+        let z = Vector::<f64, 3>::new([ 0.0, 0.0, 1.0 ]);
+        let rotation = Motor::from( Rotor3::<f64>::from_axis_angle( z, std::f64::consts::FRAC_PI_2 ) );
+        let translation = Motor::<f64>::from_translation( Vector::new([ 1.0, 0.0, 0.0 ]) );
+
+        // Rotate first, then translate
+        let res = ( translation * rotation ).rotate( Point::new( 1.0, 0.0, 0.0 ) );
+
+        assert_position( res.position(), [ 1.0, 1.0, 0.0 ] );
+        assert_rotor( translation * rotation * ( translation * rotation ).reverse(), Motor::new( 1.0, BiVector::zero() ) );
+    }
+
+    #[test]
+    fn screw() {
+        // A quarter turn about the z parallel through ( 1, 0, 0 ), rising by 0.5
+        let axis = Line::<f64>::new( Vector::new([ 0.0, 0.0, 2.0 ]), Vector::new([ 0.0, -2.0, 0.0 ]) );
+
+        let motor = Motor::from_screw( axis, 1.0 / std::f64::consts::PI, std::f64::consts::FRAC_PI_2 );
+
+        assert_position( motor.rotate( Point::new( 2.0, 0.0, 0.0 ) ).position(), [ 1.0, 1.0, 0.5 ] );
+        assert_position( motor.rotate( Point::new( 1.0, 0.0, 3.0 ) ).position(), [ 1.0, 0.0, 3.5 ] );
+    }
+
+    #[test]
+    fn exp_log() {
+        for line in [
+            Line::<f64>::from( BiVector::new([ 0.3, -0.2, 1.1, 0.5, -0.7, 0.4 ]) ),
+            Line::from( BiVector::new([ 1e-7, 0.0, 1.1, -2e-7, -0.7, 0.4 ]) ),
+            Line::from( BiVector::new([ 0.0, 0.0, 1.1, 0.0, -0.7, 0.4 ]) )
+        ] {
+            let motor = line.exp();
+            let res = motor.log();
+
+            assert!( ( motor.scalar_product( motor.reverse() ) - 1.0 ).abs() < EPSILON );
+            assert!( res.iter().zip( line.iter() ).all( |( a, b )| ( a - b ).abs() < EPSILON ) );
+        }
+    }
+
+    #[test]
+    fn exp_translation() {
+        let translation = Vector::<f64, 3>::new([ 1.0, -2.0, 0.5 ]);
+
+        let res = Motor::<f64>::from_translation( translation ).log().exp();
+
+        assert_position( res.rotate( Point::new( 0.0, 0.0, 0.0 ) ).position(), [ 1.0, -2.0, 0.5 ] );
+    }
+
+    #[test]
+    fn unitize() {
+        let motor = Motor::from_screw( Line::<f64>::new( Vector::new([ 1.0, 2.0, 0.0 ]), Vector::new([ 0.0, 0.0, 1.0 ]) ), 0.3, 0.8 );
+        let drifted = Motor::new_even( motor.scalar() * 2.0, *motor.bivector() * 2.0, QuadVector::new([ motor.quadvector()[ 0 ] * 2.0 + 0.1 ]) );
+
+        let res = drifted.unitize();
+
+        assert_rotor( res * res.reverse(), Motor::new( 1.0, BiVector::zero() ) );
+    }
+}
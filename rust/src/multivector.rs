@@ -1,17 +1,1186 @@
 // Copyright 2024 Bewusstsein Labs
 
-pub mod scalar;
-pub mod scalar_vector;
-pub mod scalar_vector_bivector;
-pub mod scalar_vector_bivector_trivector;
-pub mod scalar_vector_trivector;
-pub mod scalar_bivector;
-pub mod scalar_bivector_trivector;
-pub mod scalar_trivector;
-pub mod vector;
-pub mod vector_bivector;
-pub mod vector_bivector_trivector;
-pub mod vector_trivector;
-pub mod bivector;
-pub mod bivector_trivector;
-pub mod trivector;
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{ Neg, Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign }
+};
+use num::traits::Num;
+
+use linear_algebra::vector::Vector;
+
+use crate::{
+    ops::{
+        ExteriorProduct,
+        RegressiveProduct,
+        LeftContraction,
+        RightContraction,
+        HestenesInnerProduct,
+        FatDot,
+        ScalarProduct,
+        Reverse,
+        GradeInvolution,
+        CliffordConjugate,
+        Dual,
+        Undual,
+        HodgeStar,
+        GeometricAdd,
+        GeometricSub,
+        GeometricProduct
+    },
+    traits::{
+        ScalarComponent,
+        ScalarComponentMut,
+        BiVectorComponent,
+        QuadVectorComponent
+    },
+    blade::{
+        self,
+        binomial
+    },
+    metric::{
+        Metric,
+        Euclidean
+    },
+    kvector::KVector,
+    bivector::BiVector,
+    trivector::TriVector,
+    quadvector::QuadVector,
+    rotor::Rotor
+};
+
+/// A multivector type of generic element and size.
+///
+/// Stores one coefficient per basis blade, 2^DIM in total. The coefficient of the blade
+/// e_i ∧ e_j ∧ ... is stored at the index whose set bits are i, j, ...
+///
+/// The metric M decides what the basis vectors square to in the geometric product, the products
+/// built on it and the dual, it defaults to [`Euclidean`]. The exterior and regressive products and
+/// the Hodge star do not depend on it.
+///
+#[derive( Clone, Copy, Debug )]
+pub struct Multivector<T, const DIM: usize, M = Euclidean>( [T; 1 << DIM], PhantomData<M> )
+where
+    T: 'static + Default + Copy + Debug,
+    M: Metric,
+    [(); 1 << DIM]:;
+
+impl<T, const DIM: usize, M> Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    /// Creates a new const [`Multivector`].
+    ///
+    pub const fn new_const( src: [T; 1 << DIM] ) -> Self {
+        Self ( src, PhantomData )
+    }
+
+    /// Creates a new [`Multivector`].
+    ///
+    pub fn new( src: [T; 1 << DIM] ) -> Self {
+        Self ( src, PhantomData )
+    }
+
+    /// Creates a new zero filled [`Multivector`].
+    ///
+    pub fn zero() -> Self
+    where
+        T: Num
+    {
+        Self ( [T::zero(); 1 << DIM], PhantomData )
+    }
+
+    /// Returns an iterator over the elements of the [`Multivector`].
+    ///
+    /// The iterator yields references to the elements of the [`Multivector`] in blade order.
+    ///
+    pub fn iter( &self ) -> impl Iterator<Item = &T> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the [`Multivector`].
+    ///
+    /// The iterator yields mutable references to the elements of the [`Multivector`] in blade order.
+    ///
+    pub fn iter_mut( &mut self ) -> impl Iterator<Item = &mut T> {
+        self.0.iter_mut()
+    }
+
+    /// Returns the grade `grade` part of the [`Multivector`], with every other coefficient zeroed.
+    ///
+    pub fn grade( &self, grade: usize ) -> Self
+    where
+        T: Num
+    {
+        let mut res = Self::zero();
+        self.iter().zip( res.iter_mut() ).enumerate()
+            .filter( |( mask, _ )| blade::grade( *mask ) == grade )
+            .for_each( |( _, ( &a, c ) )| *c = a );
+        res
+    }
+
+    /// Returns the vector part of the [`Multivector`].
+    ///
+    pub fn vector( &self ) -> Vector<T, DIM> {
+        let mut res = Vector::<T, DIM>::default();
+        for i in 0..DIM {
+            res[ i ] = self.0[ blade::blade_mask( DIM, 1, i ) ];
+        }
+        res
+    }
+
+    /// Returns the grade `GRADE` part of the [`Multivector`] as a [`KVector`].
+    ///
+    pub fn kvector<const GRADE: usize>( &self ) -> KVector<T, DIM, GRADE>
+    where
+        [(); binomial( DIM, GRADE )]:
+    {
+        let mut res = KVector::<T, DIM, GRADE>::default();
+        res.iter_mut().enumerate()
+            .for_each( |( i, a )| *a = self.0[ blade::blade_mask( DIM, GRADE, i ) ] );
+        res
+    }
+
+    /// Returns the bivector part of the [`Multivector`].
+    ///
+    pub fn bivector( &self ) -> BiVector<T, DIM>
+    where
+        [(); binomial( DIM, 2 )]:
+    {
+        self.kvector::<2>()
+    }
+
+    /// Returns the trivector part of the [`Multivector`].
+    ///
+    pub fn trivector( &self ) -> TriVector<T, DIM>
+    where
+        [(); binomial( DIM, 3 )]:
+    {
+        self.kvector::<3>()
+    }
+
+    /// Returns the quadvector part of the [`Multivector`].
+    ///
+    pub fn quadvector( &self ) -> QuadVector<T, DIM>
+    where
+        [(); binomial( DIM, 4 )]:
+    {
+        self.kvector::<4>()
+    }
+
+    /// Returns the scalar, bivector and quadvector part of the [`Multivector`] as a [`Rotor`].
+    ///
+    pub fn rotor( &self ) -> Rotor<T, DIM, M>
+    where
+        [(); binomial( DIM, 2 )]:,
+        [(); binomial( DIM, 4 )]:
+    {
+        Rotor::new_even( self.0[ 0 ], self.kvector::<2>(), self.kvector::<4>() )
+    }
+
+    /// Returns the geometric product of `self` and `rhs` restricted to the pairs of basis blades for
+    /// which `keep( lhs grade, rhs grade, product grade )` holds.
+    ///
+    /// Every kept pair of basis blades e_A e_B contributes ±e_(A xor B) or nothing, the factor coming from
+    /// reordering the basis vectors into canonical order and the metric squares of the shared ones.
+    ///
+    pub fn graded_product( self, rhs: Self, keep: impl Fn( usize, usize, usize ) -> bool ) -> Self
+    where
+        T: Num
+    {
+        let mut res = Self::zero();
+        for ( a, &lhs ) in self.iter().enumerate() {
+            if lhs == T::zero() {
+                continue;
+            }
+            for ( b, &other ) in rhs.iter().enumerate() {
+                if !keep( blade::grade( a ), blade::grade( b ), blade::grade( a ^ b ) ) {
+                    continue;
+                }
+                match M::product( a, b ) {
+                    1 => res[ a ^ b ] = res[ a ^ b ] + lhs * other,
+                    -1 => res[ a ^ b ] = res[ a ^ b ] - lhs * other,
+                    _ => {}
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<T, const DIM: usize, M> ScalarComponent<T> for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn scalar( &self ) -> &T {
+        &self.0[ 0 ]
+    }
+}
+
+impl<T, const DIM: usize, M> ScalarComponentMut<T> for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn scalar_mut( &mut self ) -> &mut T {
+        &mut self.0[ 0 ]
+    }
+}
+
+impl<T, const DIM: usize, M> Deref for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Target = [T; 1 << DIM];
+
+    fn deref( &self ) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const DIM: usize, M> DerefMut for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn deref_mut( &mut self ) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, const DIM: usize, M> Index<usize> for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = T;
+
+    fn index( &self, index: usize ) -> &Self::Output {
+        &self.0[ index ]
+    }
+}
+
+impl<T, const DIM: usize, M> IndexMut<usize> for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn index_mut( &mut self, index: usize ) -> &mut Self::Output {
+        &mut self.0[ index ]
+    }
+}
+
+impl<T, const DIM: usize, M> Default for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn default() -> Self {
+        Self ( [T::default(); 1 << DIM], PhantomData )
+    }
+}
+
+impl<T, const DIM: usize, M> From<[T; 1 << DIM]> for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn from( src: [T; 1 << DIM] ) -> Self {
+        Self ( src, PhantomData )
+    }
+}
+
+impl<T, const DIM: usize, M> From<T> for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn from( src: T ) -> Self {
+        let mut res = Self::default();
+        res.0[ 0 ] = src;
+        res
+    }
+}
+
+impl<T, const DIM: usize, M> From<Vector<T, DIM>> for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn from( src: Vector<T, DIM> ) -> Self {
+        let mut res = Self::default();
+        for i in 0..DIM {
+            res.0[ blade::blade_mask( DIM, 1, i ) ] = src[ i ];
+        }
+        res
+    }
+}
+
+impl<T, const DIM: usize, const GRADE: usize, M> From<KVector<T, DIM, GRADE>> for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    fn from( src: KVector<T, DIM, GRADE> ) -> Self {
+        let mut res = Self::default();
+        src.iter().enumerate()
+            .for_each( |( i, &a )| res.0[ blade::blade_mask( DIM, GRADE, i ) ] = a );
+        res
+    }
+}
+
+impl<T, const DIM: usize, M> From<Rotor<T, DIM, M>> for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    fn from( src: Rotor<T, DIM, M> ) -> Self {
+        let mut res = Self::from( *src.bivector() );
+        res.0[ 0 ] = *src.scalar();
+        src.quadvector().iter().enumerate()
+            .for_each( |( i, &a )| res.0[ blade::blade_mask( DIM, 4, i ) ] = a );
+        res
+    }
+}
+
+impl<T, const DIM: usize, M> PartialEq for Multivector<T, DIM, M>
+where
+    T: 'static + Copy + Default + Debug + PartialEq,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn eq( &self, other: &Self ) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T, const DIM: usize, M> Neg for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn neg( mut self ) -> Self::Output {
+        self.iter_mut()
+            .for_each( |a| *a = -*a );
+        self
+    }
+}
+
+impl<T, const DIM: usize, M> Add for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Add<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn add( mut self, other: Self ) -> Self::Output {
+        self.iter_mut().zip( other.iter() )
+            .for_each( |( a, &b )| *a = *a + b );
+        self
+    }
+}
+
+impl<T, const DIM: usize, M> Sub for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Sub<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn sub( mut self, other: Self ) -> Self::Output {
+        self.iter_mut().zip( other.iter() )
+            .for_each( |( a, &b )| *a = *a - b );
+        self
+    }
+}
+
+impl<T, const DIM: usize, M> Mul<T> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Mul<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn mul( mut self, scalar: T ) -> Self::Output {
+        self.iter_mut()
+            .for_each( |a| *a = *a * scalar );
+        self
+    }
+}
+
+impl<T, const DIM: usize, M> Div<T> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Div<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn div( mut self, scalar: T ) -> Self::Output {
+        self.iter_mut()
+            .for_each( |a| *a = *a / scalar );
+        self
+    }
+}
+
+impl<T, const DIM: usize, M> AddAssign for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + AddAssign,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn add_assign( &mut self, other: Self ) {
+        self.iter_mut().zip( other.iter() )
+            .for_each( |( a, &b )| *a += b );
+    }
+}
+
+impl<T, const DIM: usize, M> SubAssign for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + SubAssign,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn sub_assign( &mut self, other: Self ) {
+        self.iter_mut().zip( other.iter() )
+            .for_each( |( a, &b )| *a -= b );
+    }
+}
+
+impl<T, const DIM: usize, M> MulAssign<T> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + MulAssign,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn mul_assign( &mut self, scalar: T ) {
+        self.iter_mut()
+            .for_each( |a| *a *= scalar );
+    }
+}
+
+impl<T, const DIM: usize, M> DivAssign<T> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + DivAssign,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    fn div_assign( &mut self, scalar: T ) {
+        self.iter_mut()
+            .for_each( |a| *a /= scalar );
+    }
+}
+
+impl<T, const DIM: usize, M> Reverse for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn reverse( mut self ) -> Self::Output {
+        self.iter_mut().enumerate()
+            .filter( |( mask, _ )| blade::reverse_negates( blade::grade( *mask ) ) )
+            .for_each( |( _, a )| *a = -*a );
+        self
+    }
+}
+
+impl<T, const DIM: usize, M> GradeInvolution for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn grade_involution( mut self ) -> Self::Output {
+        self.iter_mut().enumerate()
+            .filter( |( mask, _ )| blade::involution_negates( blade::grade( *mask ) ) )
+            .for_each( |( _, a )| *a = -*a );
+        self
+    }
+}
+
+impl<T, const DIM: usize, M> CliffordConjugate for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn clifford_conjugate( mut self ) -> Self::Output {
+        self.iter_mut().enumerate()
+            .filter( |( mask, _ )| blade::conjugate_negates( blade::grade( *mask ) ) )
+            .for_each( |( _, a )| *a = -*a );
+        self
+    }
+}
+
+/// Maps every basis blade of `src` to its complement, negating the blades for which `negates( blade )`.
+///
+/// Uses the Euclidean signs whatever the metric, so the maps stay defined in degenerate metrics, where I
+/// has no inverse and they reduce to the Poincaré duality the regressive product is built on.
+///
+fn complement<T, const DIM: usize, M>( src: Multivector<T, DIM, M>, negates: impl Fn( usize ) -> bool ) -> Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    let mut res = Multivector::<T, DIM, M>::default();
+    for ( a, value ) in src.iter().enumerate() {
+        res[ blade::pseudoscalar_mask( DIM ) ^ a ] = if negates( a ) { -*value } else { *value };
+    }
+    res
+}
+
+/// Returns `true` if the basis vectors of the blade `mask` square to -1 an odd number of times in the metric M.
+///
+fn squares_negate<M>( mask: usize ) -> bool
+where
+    M: Metric
+{
+    ( 0..usize::BITS as usize )
+        .filter( |index| mask >> index & 1 != 0 )
+        .fold( false, |acc, index| acc ^ ( M::square( index ) < 0 ) )
+}
+
+/// A* = A I⁻¹ in the metric M.
+///
+/// Panics if M is degenerate, I has no inverse there. The regressive product and the Hodge star are
+/// built on the metric free complement instead and stay defined.
+///
+impl<T, const DIM: usize, M> Dual for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn dual( self ) -> Self::Output {
+        assert!( !M::is_degenerate( DIM ), "the dual is undefined in a degenerate metric" );
+        complement( self, |a| blade::dual_negates( DIM, a ) != squares_negate::<M>( blade::pseudoscalar_mask( DIM ) ^ a ) )
+    }
+}
+
+/// A = A* I in the metric M.
+///
+/// Panics if M is degenerate, like [`Dual`].
+///
+impl<T, const DIM: usize, M> Undual for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn undual( self ) -> Self::Output {
+        assert!( !M::is_degenerate( DIM ), "the undual is undefined in a degenerate metric" );
+        complement( self, |a| blade::undual_negates( DIM, a ) != squares_negate::<M>( a ) )
+    }
+}
+
+/// ⋆A, the Euclidean Hodge star whatever the metric M, i.e. the signed complement of every basis blade.
+///
+impl<T, const DIM: usize, M> HodgeStar for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Self;
+
+    fn hodge_star( self ) -> Self::Output {
+        complement( self, |a| blade::hodge_negates( DIM, a ) )
+    }
+}
+
+/// Multivector + Multivector
+///
+impl<T, const DIM: usize, M> GeometricAdd for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Add<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_add( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        self + rhs
+    }
+}
+
+/// Multivector - Multivector
+///
+impl<T, const DIM: usize, M> GeometricSub for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Sub<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_sub( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        self - rhs
+    }
+}
+
+/// Multivector * Multivector
+///
+impl<T, const DIM: usize, M> GeometricProduct for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_product( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        self.graded_product( rhs, |_, _, _| true )
+    }
+}
+
+/// Multivector ⌋ Multivector
+///
+impl<T, const DIM: usize, M> LeftContraction for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn left_contraction( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        self.graded_product( rhs, |a, b, c| b >= a && c == b - a )
+    }
+}
+
+/// Multivector ⌊ Multivector
+///
+impl<T, const DIM: usize, M> RightContraction for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn right_contraction( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        self.graded_product( rhs, |a, b, c| a >= b && c == a - b )
+    }
+}
+
+/// Multivector · Multivector
+///
+impl<T, const DIM: usize, M> HestenesInnerProduct for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn hestenes_inner_product( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        self.graded_product( rhs, |a, b, c| a != 0 && b != 0 && c == a.abs_diff( b ) )
+    }
+}
+
+/// Multivector • Multivector
+///
+impl<T, const DIM: usize, M> FatDot for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn fat_dot( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        self.graded_product( rhs, |a, b, c| c == a.abs_diff( b ) )
+    }
+}
+
+/// Multivector * Multivector, grade 0 part
+///
+impl<T, const DIM: usize, M> ScalarProduct for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = T;
+
+    fn scalar_product( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        self.graded_product( rhs, |_, _, c| c == 0 )[ 0 ]
+    }
+}
+
+/// Multivector ∧ Multivector
+///
+impl<T, const DIM: usize, M> ExteriorProduct for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn exterior_product( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        self.graded_product( rhs, |a, b, c| c == a + b )
+    }
+}
+
+/// Multivector ∨ Multivector
+///
+/// Uses the Euclidean signs whatever the metric, like [`Dual`], so it is defined in degenerate metrics too.
+///
+impl<T, const DIM: usize, M> RegressiveProduct for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn regressive_product( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        let mut res = Self::zero();
+        for ( a, &lhs ) in self.iter().enumerate() {
+            for ( b, &other ) in rhs.iter().enumerate() {
+                if a | b != blade::pseudoscalar_mask( DIM ) {
+                    continue;
+                }
+                if blade::regressive_negates( DIM, a, b ) {
+                    res[ a & b ] = res[ a & b ] - lhs * other;
+                } else {
+                    res[ a & b ] = res[ a & b ] + lhs * other;
+                }
+            }
+        }
+        res
+    }
+}
+
+/// Multivector + Vector<T, DIM>
+///
+impl<T, const DIM: usize, M> GeometricAdd<Vector<T, DIM>> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Add<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_add( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        self + Multivector::<T, DIM, M>::from( rhs )
+    }
+}
+
+/// Multivector - Vector<T, DIM>
+///
+impl<T, const DIM: usize, M> GeometricSub<Vector<T, DIM>> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Sub<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_sub( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        self - Multivector::<T, DIM, M>::from( rhs )
+    }
+}
+
+/// Multivector * Vector<T, DIM>
+///
+impl<T, const DIM: usize, M> GeometricProduct<Vector<T, DIM>> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        self.geometric_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Vector<T, DIM> + Multivector
+///
+impl<T, const DIM: usize, M> GeometricAdd<Multivector<T, DIM, M>> for Vector<T, DIM>
+where
+    T: Default + Copy + Debug + Add<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_add( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ) + rhs
+    }
+}
+
+/// Vector<T, DIM> - Multivector
+///
+impl<T, const DIM: usize, M> GeometricSub<Multivector<T, DIM, M>> for Vector<T, DIM>
+where
+    T: Default + Copy + Debug + Sub<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_sub( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ) - rhs
+    }
+}
+
+/// Vector<T, DIM> * Multivector
+///
+impl<T, const DIM: usize, M> GeometricProduct<Multivector<T, DIM, M>> for Vector<T, DIM>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_product( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).geometric_product( rhs )
+    }
+}
+
+/// Multivector + KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> GeometricAdd<KVector<T, DIM, GRADE>> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Add<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_add( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        self + Multivector::<T, DIM, M>::from( rhs )
+    }
+}
+
+/// Multivector - KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> GeometricSub<KVector<T, DIM, GRADE>> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Sub<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_sub( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        self - Multivector::<T, DIM, M>::from( rhs )
+    }
+}
+
+/// Multivector * KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> GeometricProduct<KVector<T, DIM, GRADE>> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_product( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        self.geometric_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// KVector<T, DIM, GRADE> + Multivector
+///
+impl<T, const DIM: usize, const GRADE: usize, M> GeometricAdd<Multivector<T, DIM, M>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Add<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_add( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ) + rhs
+    }
+}
+
+/// KVector<T, DIM, GRADE> - Multivector
+///
+impl<T, const DIM: usize, const GRADE: usize, M> GeometricSub<Multivector<T, DIM, M>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Sub<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_sub( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ) - rhs
+    }
+}
+
+/// KVector<T, DIM, GRADE> * Multivector
+///
+impl<T, const DIM: usize, const GRADE: usize, M> GeometricProduct<Multivector<T, DIM, M>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_product( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).geometric_product( rhs )
+    }
+}
+
+/// Multivector + Rotor<T, DIM, M>
+///
+impl<T, const DIM: usize, M> GeometricAdd<Rotor<T, DIM, M>> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Add<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_add( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        self + Multivector::<T, DIM, M>::from( rhs )
+    }
+}
+
+/// Multivector - Rotor<T, DIM, M>
+///
+impl<T, const DIM: usize, M> GeometricSub<Rotor<T, DIM, M>> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Sub<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_sub( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        self - Multivector::<T, DIM, M>::from( rhs )
+    }
+}
+
+/// Multivector * Rotor<T, DIM, M>
+///
+impl<T, const DIM: usize, M> GeometricProduct<Rotor<T, DIM, M>> for Multivector<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_product( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        self.geometric_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM, M> + Multivector
+///
+impl<T, const DIM: usize, M> GeometricAdd<Multivector<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Add<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_add( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ) + rhs
+    }
+}
+
+/// Rotor<T, DIM, M> - Multivector
+///
+impl<T, const DIM: usize, M> GeometricSub<Multivector<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Sub<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_sub( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ) - rhs
+    }
+}
+
+/// Rotor<T, DIM, M> * Multivector
+///
+impl<T, const DIM: usize, M> GeometricProduct<Multivector<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn geometric_product( self, rhs: Multivector<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).geometric_product( rhs )
+    }
+}
+
+pub type Multivector2<T> = Multivector<T, 2>;
+pub type Multivector3<T> = Multivector<T, 3>;
+pub type Multivector4<T> = Multivector<T, 4>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linear_algebra::vector::Vector;
+    use crate::{
+        ops::{
+            GeometricProduct,
+            Reverse,
+            GradeInvolution,
+            CliffordConjugate,
+            Dual,
+            Undual
+        },
+        metric::{
+            Projective3,
+            Spacetime
+        }
+    };
+
+    #[test]
+    fn geometric_product_vector_vector_3() {
+        let lhs = Vector::<f64, 3>::new([ 4.0, 5.0, 6.0 ]);
+        let rhs = Vector::<f64, 3>::new([ 8.0, 3.0, 1.0 ]);
+
+        let result = Multivector::from( lhs ).geometric_product( rhs );
+
+        assert_eq!(
+            result,
+            Multivector::<f64, 3>::new([
+                lhs[ 0 ] * rhs[ 0 ] + lhs[ 1 ] * rhs[ 1 ] + lhs[ 2 ] * rhs[ 2 ], // 1
+                0.0, // x
+                0.0, // y
+                lhs[ 0 ] * rhs[ 1 ] - lhs[ 1 ] * rhs[ 0 ], // xy
+                0.0, // z
+                lhs[ 0 ] * rhs[ 2 ] - lhs[ 2 ] * rhs[ 0 ], // xz
+                lhs[ 1 ] * rhs[ 2 ] - lhs[ 2 ] * rhs[ 1 ], // yz
+                0.0, // xyz
+            ])
+        );
+    }
+
+    #[test]
+    fn involutions_3() {
+        let a = Multivector::<f64, 3>::new([ 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0 ]);
+
+        assert_eq!( a.reverse(), Multivector::new([ 1.0, 2.0, 3.0, -4.0, 5.0, -6.0, -7.0, -8.0 ]) );
+        assert_eq!( a.grade_involution(), Multivector::new([ 1.0, -2.0, -3.0, 4.0, -5.0, 6.0, 7.0, -8.0 ]) );
+        assert_eq!( a.clifford_conjugate(), Multivector::new([ 1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, 8.0 ]) );
+    }
+
+    #[test]
+    fn reverse_of_product_3() {
+        let a = Multivector::<f64, 3>::new([ 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0 ]);
+        let b = Multivector::<f64, 3>::new([ 8.0, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0 ]);
+
+        assert_eq!( a.geometric_product( b ).reverse(), b.reverse().geometric_product( a.reverse() ) );
+    }
+
+    #[test]
+    fn geometric_product_pseudoscalar_3() {
+        let xyz = Multivector::<f64, 3>::new([ 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0 ]);
+
+        assert_eq!( xyz.geometric_product( xyz ), Multivector::from( -1.0 ) );
+    }
+
+    #[test]
+    fn geometric_product_spacetime() {
+        let t = Multivector::<f64, 4, Spacetime>::from( Vector::<f64, 4>::new([ 1.0, 0.0, 0.0, 0.0 ]) );
+        let x = Multivector::<f64, 4, Spacetime>::from( Vector::<f64, 4>::new([ 0.0, 1.0, 0.0, 0.0 ]) );
+
+        assert_eq!( t.geometric_product( t ), Multivector::from( 1.0 ) );
+        assert_eq!( x.geometric_product( x ), Multivector::from( -1.0 ) );
+        assert_eq!( t.geometric_product( x ), -x.geometric_product( t ) );
+    }
+
+    #[test]
+    fn geometric_product_projective() {
+        let e1 = Multivector::<f64, 4, Projective3>::from( Vector::<f64, 4>::new([ 1.0, 0.0, 0.0, 0.0 ]) );
+        let e0 = Multivector::<f64, 4, Projective3>::from( Vector::<f64, 4>::new([ 0.0, 0.0, 0.0, 1.0 ]) );
+
+        assert_eq!( e1.geometric_product( e1 ), Multivector::from( 1.0 ) );
+        assert_eq!( e0.geometric_product( e0 ), Multivector::zero() );
+    }
+
+    #[test]
+    fn dual_spacetime() {
+        let a = Multivector::<f64, 4, Spacetime>::new( std::array::from_fn( |i| i as f64 + 1.0 ) );
+        let mut pseudoscalar = Multivector::<f64, 4, Spacetime>::zero();
+        pseudoscalar[ 15 ] = 1.0;
+
+        // I² = -1 in Cl(1, 3), so I⁻¹ = -I
+        assert_eq!( a.dual(), -a.geometric_product( pseudoscalar ) );
+        assert_eq!( a.undual(), a.geometric_product( pseudoscalar ) );
+        assert_eq!( a.dual().undual(), a );
+    }
+
+    #[test]
+    #[should_panic]
+    fn dual_projective() {
+        Multivector::<f64, 4, Projective3>::from( 1.0 ).dual();
+    }
+}
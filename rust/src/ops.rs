@@ -11,12 +11,76 @@ pub trait InteriorProduct<Rhs = Self> {
     fn interior_product( self, rhs: Rhs ) -> Self::Output;
 }
 
+/// The interior product is the left contraction.
+///
+impl<Lhs, Rhs> InteriorProduct<Rhs> for Lhs
+where
+    Lhs: LeftContraction<Rhs>
+{
+    type Output = <Lhs as LeftContraction<Rhs>>::Output;
+
+    fn interior_product( self, rhs: Rhs ) -> Self::Output {
+        self.left_contraction( rhs )
+    }
+}
+
+/// A ⌋ B, the grade |B| - |A| part of A B, zero when |A| > |B|.
+///
+pub trait LeftContraction<Rhs = Self> {
+    type Output;
+
+    fn left_contraction( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// A ⌊ B, the grade |A| - |B| part of A B, zero when |B| > |A|.
+///
+pub trait RightContraction<Rhs = Self> {
+    type Output;
+
+    fn right_contraction( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// A · B, the grade ||A| - |B|| part of A B, zero when either is a scalar.
+///
+pub trait HestenesInnerProduct<Rhs = Self> {
+    type Output;
+
+    fn hestenes_inner_product( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// A • B, the grade ||A| - |B|| part of A B, scalars included.
+///
+pub trait FatDot<Rhs = Self> {
+    type Output;
+
+    fn fat_dot( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// A * B, the grade 0 part of A B.
+///
+pub trait ScalarProduct<Rhs = Self> {
+    type Output;
+
+    fn scalar_product( self, rhs: Rhs ) -> Self::Output;
+}
+
 pub trait ExteriorProduct<Rhs = Self> {
     type Output;
 
     fn exterior_product( self, rhs: Rhs ) -> Self::Output;
 }
 
+/// A ∨ B, the regressive product, dual to the exterior product: A ∨ B = (A* ∧ B*) I with A* = A I⁻¹.
+///
+/// Maps grades k and l to grade k + l - DIM. Where A and B together span the whole space it returns their
+/// intersection, e.g. the line two planes meet in, and I ∨ A = A ∨ I = A.
+///
+pub trait RegressiveProduct<Rhs = Self> {
+    type Output;
+
+    fn regressive_product( self, rhs: Rhs ) -> Self::Output;
+}
+
 pub trait GeometricAdd<Rhs = Self> {
     type Output;
 
@@ -34,3 +98,116 @@ pub trait GeometricProduct<Rhs = Self> {
 
     fn geometric_product( self, rhs: Rhs ) -> Self::Output;
 }
+
+/// Ã, reverses the order of the basis vectors of every blade, negating grades 2, 3, 6, 7, ...
+///
+pub trait Reverse {
+    type Output;
+
+    fn reverse( self ) -> Self::Output;
+}
+
+/// Â, maps every basis vector to its negative, negating the odd grades.
+///
+pub trait GradeInvolution {
+    type Output;
+
+    fn grade_involution( self ) -> Self::Output;
+}
+
+/// A̅, the reverse of the grade involution, negating grades 1, 2, 5, 6, ...
+///
+pub trait CliffordConjugate {
+    type Output;
+
+    fn clifford_conjugate( self ) -> Self::Output;
+}
+
+/// A*, maps grade k to grade DIM - k, A* = A I⁻¹.
+///
+pub trait Dual {
+    type Output;
+
+    fn dual( self ) -> Self::Output;
+}
+
+/// The inverse of [`Dual`], A = A* I.
+///
+pub trait Undual {
+    type Output;
+
+    fn undual( self ) -> Self::Output;
+}
+
+/// ⋆A, maps grade k to grade DIM - k such that A ∧ ⋆A = |A|² I.
+///
+pub trait HodgeStar {
+    type Output;
+
+    fn hodge_star( self ) -> Self::Output;
+}
+
+/// R X R̃, rotates X by the rotor R.
+///
+pub trait Rotate<Rhs> {
+    type Output;
+
+    fn rotate( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// A ∨ B, the smallest element containing both A and B, e.g. the line through two points.
+///
+pub trait Join<Rhs = Self> {
+    type Output;
+
+    fn join( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// A ∧ B, the largest element contained in both A and B, e.g. the point two lines cross in.
+///
+pub trait Meet<Rhs = Self> {
+    type Output;
+
+    fn meet( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// A projected onto B, the element incident with B closest to A, e.g. the foot of the perpendicular from a point
+/// onto a line.
+///
+pub trait Project<Rhs> {
+    type Output;
+
+    fn project( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// The Euclidean distance between A and B, zero if they meet.
+///
+pub trait Distance<Rhs = Self> {
+    type Output;
+
+    fn distance( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// The angle between A and B in radians, in [ 0, π ].
+///
+pub trait Angle<Rhs = Self> {
+    type Output;
+
+    fn angle( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// A reflected in B, e.g. the mirror image of a point in a plane.
+///
+pub trait Reflect<Rhs> {
+    type Output;
+
+    fn reflect( self, rhs: Rhs ) -> Self::Output;
+}
+
+/// The signed Euclidean distance between a point and a line or plane, positive on the side its normal points to.
+///
+pub trait SignedDistance<Rhs> {
+    type Output;
+
+    fn signed_distance( self, rhs: Rhs ) -> Self::Output;
+}
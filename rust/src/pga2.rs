@@ -0,0 +1,696 @@
+// Copyright 2024 Bewusstsein Labs
+
+use std::{
+    fmt::Debug,
+    ops::Deref
+};
+use num::traits::{ Num, Float };
+
+use linear_algebra::{
+    ops::Normalize,
+    vector::Vector
+};
+
+use crate::{
+    ops::{
+        ExteriorProduct,
+        RegressiveProduct,
+        Rotate,
+        Join,
+        Meet,
+        Project,
+        Distance,
+        SignedDistance,
+        Angle
+    },
+    traits::{
+        ScalarComponent,
+        BiVectorComponent
+    },
+    metric::Projective2,
+    bivector::BiVector,
+    rotor::{
+        Rotor,
+        Rotor2
+    }
+};
+
+/// A line a x + b y + c = 0 of 2D projective geometric algebra, the vector a e1 + b e2 + c e0.
+///
+/// PGA2 is Cl(2, 0, 1), see [`Projective2`], its degenerate basis vector e0 is stored last, as w. Lines are vectors
+/// and points bivectors, so the exterior product meets and the regressive product joins. The line runs along
+/// ( -b, a ), its normal turned counterclockwise, and c w with a = b = 0 is the ideal line at infinity.
+///
+#[derive( Clone, Copy, Debug, Default, PartialEq )]
+pub struct Line<T>( Vector<T, 3> )
+where
+    T: 'static + Default + Copy + Debug;
+
+/// A point of 2D projective geometric algebra, a bivector of PGA2 in the order xy, xw, yw with w = e0.
+///
+/// The point ( x, y ) is the meet of the lines through it parallel to the axes, xy - y xw + x yw, and any nonzero
+/// multiple of it. The xy component is its weight, a point of weight zero is an ideal point, the direction shared by
+/// all lines parallel to ( x, y ). Two parallel lines meet in such a point.
+///
+#[derive( Clone, Copy, Debug, Default, PartialEq )]
+pub struct Point<T>( BiVector<T, 3> )
+where
+    T: 'static + Default + Copy + Debug;
+
+/// A motor, a rigid body motion of 2D projective geometric algebra.
+///
+/// The even subalgebra of PGA2: a scalar and the bivector xy, xw, yw, with w = e0. It is a [`Rotor`] in the metric
+/// [`Projective2`], so it composes with `*`, `self * rhs` applying `rhs` first, and moves lines and points by the
+/// sandwich M X M̃ through [`Rotate`]. A [`Rotor2`] is the motor turning about the origin.
+///
+pub type Motor2<T> = Rotor<T, 3, Projective2>;
+
+impl<T> Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Creates the [`Line`] a x + b y + c = 0.
+    ///
+    pub fn new( a: T, b: T, c: T ) -> Self {
+        Self( Vector::new([ a, b, c ]) )
+    }
+
+    /// Returns the normal ( a, b ) of the [`Line`].
+    ///
+    pub fn normal( &self ) -> Vector<T, 2> {
+        Vector::new([ self.0[ 0 ], self.0[ 1 ] ])
+    }
+
+    /// Returns the direction ( -b, a ) of the [`Line`], its normal turned counterclockwise.
+    ///
+    pub fn direction( &self ) -> Vector<T, 2> {
+        Vector::new([ -self.0[ 1 ], self.0[ 0 ] ])
+    }
+
+    /// Returns the Euclidean norm of the [`Line`], the length of its normal.
+    ///
+    pub fn euclidean_norm( &self ) -> T {
+        ( self.0[ 0 ] * self.0[ 0 ] + self.0[ 1 ] * self.0[ 1 ] ).sqrt()
+    }
+
+    /// Returns the ideal norm of the [`Line`], |c|, its distance from the origin once normalized.
+    ///
+    pub fn ideal_norm( &self ) -> T {
+        self.0[ 2 ].abs()
+    }
+
+    /// Returns `true` if the Euclidean norm of the [`Line`] exceeds `epsilon`, i.e. if it is a finite line.
+    ///
+    pub fn is_euclidean( &self, epsilon: T ) -> bool {
+        self.euclidean_norm() > epsilon
+    }
+
+    /// Returns `true` if the [`Line`] is the line at infinity: its Euclidean norm is at most `epsilon`, its ideal
+    /// norm is not.
+    ///
+    pub fn is_ideal( &self, epsilon: T ) -> bool {
+        !self.is_euclidean( epsilon ) && self.ideal_norm() > epsilon
+    }
+}
+
+impl<T> Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Creates the [`Point`] ( x, y ) of weight 1.
+    ///
+    pub fn new( x: T, y: T ) -> Self {
+        Self( BiVector::new([ T::one(), -y, x ]) )
+    }
+
+    /// Creates the ideal [`Point`] in the direction ( x, y ).
+    ///
+    pub fn ideal( x: T, y: T ) -> Self {
+        Self( BiVector::new([ T::zero(), -y, x ]) )
+    }
+
+    /// Returns the weight of the [`Point`], its xy component.
+    ///
+    pub fn weight( &self ) -> T {
+        self.0[ 0 ]
+    }
+
+    /// Returns the x, y part of the [`Point`], its position times its weight, or its direction if it is ideal.
+    ///
+    pub fn direction( &self ) -> Vector<T, 2> {
+        Vector::new([ self.0[ 2 ], -self.0[ 1 ] ])
+    }
+
+    /// Returns the position of the [`Point`], or `None` if it is ideal.
+    ///
+    pub fn position( &self ) -> Option<Vector<T, 2>> {
+        let weight = self.weight();
+        if weight == T::zero() {
+            return None;
+        }
+        Some( self.direction() * ( T::one() / weight ) )
+    }
+
+    /// Returns the Euclidean norm of the [`Point`], the absolute value of its weight.
+    ///
+    pub fn euclidean_norm( &self ) -> T {
+        self.0[ 0 ].abs()
+    }
+
+    /// Returns the ideal norm of the [`Point`], the length of its x, y part.
+    ///
+    pub fn ideal_norm( &self ) -> T {
+        ( self.0[ 1 ] * self.0[ 1 ] + self.0[ 2 ] * self.0[ 2 ] ).sqrt()
+    }
+
+    /// Returns `true` if the Euclidean norm of the [`Point`] exceeds `epsilon`, i.e. if it is a finite point.
+    ///
+    pub fn is_euclidean( &self, epsilon: T ) -> bool {
+        self.euclidean_norm() > epsilon
+    }
+
+    /// Returns `true` if the [`Point`] is a direction: its weight is at most `epsilon`, its ideal norm is not.
+    ///
+    pub fn is_ideal( &self, epsilon: T ) -> bool {
+        !self.is_euclidean( epsilon ) && self.ideal_norm() > epsilon
+    }
+
+    /// Returns the exponential e^P of the [`Point`] bivector as a [`Motor2`].
+    ///
+    /// P² = -w² with w the weight, so e^P = cos w + sin w / w · P, the rotation by -2w about the point. An ideal
+    /// point exponentiates to the translation 1 + P.
+    ///
+    pub fn exp( &self ) -> Motor2<T> {
+        let u = self.euclidean_norm();
+        if u == T::zero() {
+            return Motor2::new( T::one(), self.0 );
+        }
+        Motor2::new( u.cos(), self.0 * ( u.sin() / u ) )
+    }
+}
+
+impl<T> Motor2<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Creates the [`Motor2`] moving everything by `translation`, T = 1 + ½ ( t_x xw + t_y yw ).
+    ///
+    pub fn from_translation( translation: Vector<T, 2> ) -> Self {
+        let half = T::one() / ( T::one() + T::one() );
+        Motor2::new( T::one(), BiVector::new([ T::zero(), translation[ 0 ] * half, translation[ 1 ] * half ]) )
+    }
+
+    /// Creates the [`Motor2`] turning counterclockwise by `angle` radians about the finite point `center`,
+    /// M = cos( θ / 2 ) - sin( θ / 2 ) P̂.
+    ///
+    pub fn from_rotation( center: Point<T>, angle: T ) -> Self {
+        let half = angle / ( T::one() + T::one() );
+        Point::from( BiVector::from( center.normalize() ) * -half ).exp()
+    }
+
+    /// Returns the logarithm of the unit [`Motor2`], the [`Point`] bivector P with e^P = M, see [`Point::exp`].
+    ///
+    /// A motor without rotation gives its translation bivector, M = -1 has no unique logarithm.
+    ///
+    pub fn log( &self ) -> Point<T> {
+        let ( s, b ) = ( *self.scalar(), *self.bivector() );
+        let n = b[ 0 ].abs();
+        if n == T::zero() {
+            return Point::from( b );
+        }
+        Point::from( b * ( n.atan2( s ) / n ) )
+    }
+}
+
+/// The [`Motor2`] rotating like the [`Rotor2`] about the origin.
+///
+impl<T> From<Rotor2<T>> for Motor2<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: Rotor2<T> ) -> Self {
+        Motor2::new( *src.scalar(), BiVector::new([ src.bivector()[ 0 ], T::zero(), T::zero() ]) )
+    }
+}
+
+impl<T> Deref for Line<T>
+where
+    T: 'static + Copy + Default + Debug
+{
+    type Target = Vector<T, 3>;
+
+    fn deref( &self ) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Deref for Point<T>
+where
+    T: 'static + Copy + Default + Debug
+{
+    type Target = BiVector<T, 3>;
+
+    fn deref( &self ) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<Vector<T, 3>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug
+{
+    fn from( src: Vector<T, 3> ) -> Self {
+        Self( src )
+    }
+}
+
+impl<T> From<Line<T>> for Vector<T, 3>
+where
+    T: 'static + Copy + Default + Debug
+{
+    fn from( src: Line<T> ) -> Self {
+        src.0
+    }
+}
+
+impl<T> From<BiVector<T, 3>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug
+{
+    fn from( src: BiVector<T, 3> ) -> Self {
+        Self( src )
+    }
+}
+
+impl<T> From<Point<T>> for BiVector<T, 3>
+where
+    T: 'static + Copy + Default + Debug
+{
+    fn from( src: Point<T> ) -> Self {
+        src.0
+    }
+}
+
+/// Scales the [`Line`] to a unit normal, or the ideal line to c = ±1. A zero line stays zero.
+///
+impl<T> Normalize for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Self;
+
+    fn normalize( self ) -> Self::Output {
+        let norm = if self.euclidean_norm() > T::zero() { self.euclidean_norm() } else { self.ideal_norm() };
+        if norm == T::zero() {
+            return self;
+        }
+        Self( self.0 * ( T::one() / norm ) )
+    }
+}
+
+/// Scales the [`Point`] to weight 1, or an ideal point to a unit direction. A zero point stays zero.
+///
+impl<T> Normalize for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Self;
+
+    fn normalize( self ) -> Self::Output {
+        let norm = if self.weight() != T::zero() { self.weight() } else { self.ideal_norm() };
+        if norm == T::zero() {
+            return self;
+        }
+        Self( self.0 * ( T::one() / norm ) )
+    }
+}
+
+/// M Line<T> M̃
+///
+impl<T> Rotate<Line<T>> for Motor2<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Line<T>;
+
+    fn rotate( self, rhs: Line<T> ) -> Self::Output {
+        Line::from( self.rotate( *rhs ) )
+    }
+}
+
+/// M Point<T> M̃
+///
+impl<T> Rotate<Point<T>> for Motor2<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Point<T>;
+
+    fn rotate( self, rhs: Point<T> ) -> Self::Output {
+        Point::from( self.rotate( *rhs ) )
+    }
+}
+
+/// P ∨ Q, the line through both points, running from P to Q.
+///
+impl<T> Join<Point<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Line<T>;
+
+    fn join( self, rhs: Point<T> ) -> Self::Output {
+        Line::from( Vector::from( self.0.regressive_product( rhs.0 ) ) )
+    }
+}
+
+/// l ∧ m, the point both lines pass through, or their common direction if they are parallel.
+///
+impl<T> Meet<Line<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Point<T>;
+
+    fn meet( self, rhs: Line<T> ) -> Self::Output {
+        Point::from( self.0.exterior_product( rhs.0 ) )
+    }
+}
+
+/// Returns l ⌋ P, the line through `point` perpendicular to `line`, turned counterclockwise from it.
+///
+fn perpendicular<T>( line: Line<T>, point: Point<T> ) -> Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    let ( a, b ) = ( line[ 0 ], line[ 1 ] );
+    Line::new( -b * point[ 0 ], a * point[ 0 ], a * point[ 1 ] + b * point[ 2 ] )
+}
+
+/// l ∧ ( l ⌋ P ), the foot of the perpendicular from the point onto the line.
+///
+impl<T> Project<Line<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Point<T>;
+
+    fn project( self, rhs: Line<T> ) -> Self::Output {
+        rhs.meet( perpendicular( rhs, self ) )
+    }
+}
+
+/// -( l ⌋ P ) ⌋ P, the line through the point parallel to and oriented like l, scaled by the squared weight of P.
+///
+impl<T> Project<Point<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Line<T>;
+
+    fn project( self, rhs: Point<T> ) -> Self::Output {
+        Line::from( -*perpendicular( perpendicular( self, rhs ), rhs ) )
+    }
+}
+
+/// |P̂ ∨ Q̂|, infinite if either point is ideal.
+///
+impl<T> Distance<Point<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Point<T> ) -> Self::Output {
+        if self.weight() == T::zero() || rhs.weight() == T::zero() {
+            return T::infinity();
+        }
+        self.normalize().join( rhs.normalize() ).euclidean_norm()
+    }
+}
+
+/// |l̂ ∧ P̂|, infinite if the point or the line is ideal.
+///
+impl<T> Distance<Line<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Line<T> ) -> Self::Output {
+        let norm = self.euclidean_norm() * rhs.euclidean_norm();
+        if norm == T::zero() {
+            return T::infinity();
+        }
+        rhs.0.exterior_product( self.0 )[ 0 ].abs() / norm
+    }
+}
+
+/// |l̂ ∧ P̂|, infinite if the point or the line is ideal.
+///
+impl<T> Distance<Point<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Point<T> ) -> Self::Output {
+        rhs.distance( self )
+    }
+}
+
+/// Zero for crossing lines. Parallel lines meet in an ideal point whose ideal norm, once both lines are normalized,
+/// is the distance between them. Infinite if either line is ideal.
+///
+/// The lines count as parallel if the sine of the angle between them is at most √ε. It is taken between the
+/// normalized lines, so the tolerance is relative to their size, and √ε leaves room for the rounding of lines
+/// built by joins.
+///
+impl<T> Distance<Line<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Line<T> ) -> Self::Output {
+        if self.euclidean_norm() == T::zero() || rhs.euclidean_norm() == T::zero() {
+            return T::infinity();
+        }
+        let meet = self.normalize().meet( rhs.normalize() );
+        // The weight of the meet is the sine of the angle between the lines
+        if meet.euclidean_norm() > T::epsilon().sqrt() {
+            return T::zero();
+        }
+        meet.ideal_norm()
+    }
+}
+
+/// The angle between the directions of the lines, atan2( |l ∧ m|, l · m ).
+///
+impl<T> Angle<Line<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn angle( self, rhs: Line<T> ) -> Self::Output {
+        let cos = self[ 0 ] * rhs[ 0 ] + self[ 1 ] * rhs[ 1 ];
+        self.meet( rhs ).euclidean_norm().atan2( cos )
+    }
+}
+
+/// ( l ∧ P ) / ( |n| w ) = ( a x + b y + c ) / |n| for the line l with normal n = ( a, b ) and the point
+/// P = w ( x, y ), infinite if either is ideal.
+///
+impl<T> SignedDistance<Line<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn signed_distance( self, rhs: Line<T> ) -> Self::Output {
+        let norm = self.weight() * rhs.euclidean_norm();
+        if norm == T::zero() {
+            return T::infinity();
+        }
+        rhs.0.exterior_product( self.0 )[ 0 ] / norm
+    }
+}
+
+/// ( l ∧ P ) / ( |n| w ) = ( a x + b y + c ) / |n| for the line l with normal n = ( a, b ) and the point
+/// P = w ( x, y ), infinite if either is ideal.
+///
+impl<T> SignedDistance<Point<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn signed_distance( self, rhs: Point<T> ) -> Self::Output {
+        rhs.signed_distance( self )
+    }
+}
+
+/// Returns the oriented area of the triangle on the three `points`, or `None` if one of them is ideal.
+///
+/// -( P₀ ∨ P₁ ∨ P₂ ) / 2 of the normalized points, positive if they run counterclockwise and zero if they are
+/// collinear.
+///
+pub fn area<T>( points: [ Point<T>; 3 ] ) -> Option<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    if points.iter().any( |point| point.weight() == T::zero() ) {
+        return None;
+    }
+    let [ a, b, c ] = points.map( |point| point.normalize() );
+    Some( -a.join( b ).0.regressive_product( c.0 )[ 0 ] / ( T::one() + T::one() ) )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{ EPSILON, assert_position };
+
+    #[test]
+    fn line() {
+        let line = Line::<f64>::new( 3.0, 4.0, -10.0 );
+
+        let res = line.normalize();
+
+        assert_eq!( line.euclidean_norm(), 5.0 );
+        assert!( ( res.normal()[ 0 ] - 0.6 ).abs() < EPSILON );
+        assert!( ( res.direction()[ 0 ] + 0.8 ).abs() < EPSILON );
+        assert!( ( res.ideal_norm() - 2.0 ).abs() < EPSILON );
+        assert!( line.is_euclidean( EPSILON ) && !line.is_ideal( EPSILON ) );
+        assert!( Line::<f64>::new( 0.0, 0.0, 2.0 ).is_ideal( EPSILON ) );
+    }
+
+    #[test]
+    fn point() {
+        let point = Point::<f64>::new( 1.0, -2.0 );
+        let scaled = Point::from( *point * -2.0 );
+
+        assert_eq!( scaled.position(), Some( Vector::new([ 1.0, -2.0 ]) ) );
+        assert_eq!( scaled.normalize(), point );
+
+        let ideal = Point::<f64>::ideal( 3.0, 4.0 );
+
+        assert_eq!( ideal.position(), None );
+        assert!( ideal.is_ideal( EPSILON ) );
+        assert!( ( ideal.normalize().direction()[ 1 ] - 0.8 ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn join_meet() {
+        let line = Point::<f64>::new( 0.0, 0.0 ).join( Point::new( 2.0, 0.0 ) );
+
+        assert_eq!( line.normalize().direction(), Vector::new([ 1.0, 0.0 ]) );
+        assert_position( Line::new( 1.0, 0.0, -1.0 ).meet( Line::new( 0.0, 1.0, -2.0 ) ).position(), [ 1.0, 2.0 ] );
+        // A point lies on the line through it and another
+        let ( p, q ) = ( Point::<f64>::new( 1.0, 2.0 ), Point::new( -3.0, 0.5 ) );
+        assert!( p.join( q ).distance( p ) < EPSILON && p.join( q ).distance( q ) < EPSILON );
+    }
+
+    #[test]
+    fn parallel() {
+        // x = 1 and x = 3 meet in the ideal point along y
+        let ( l, m ) = ( Line::<f64>::new( 1.0, 0.0, -1.0 ), Line::new( -2.0, 0.0, 6.0 ) );
+
+        let res = l.meet( m );
+
+        assert_eq!( res.position(), None );
+        assert!( res.is_ideal( EPSILON ) );
+        assert_eq!( res.normalize().direction()[ 0 ], 0.0 );
+        assert!( ( l.distance( m ) - 2.0 ).abs() < EPSILON );
+        assert!( ( l.angle( m ) - std::f64::consts::PI ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn distance_angle() {
+        let line = Line::<f64>::new( 1.0, 1.0, -1.0 );
+
+        assert!( ( Point::new( 1.0, 2.0 ).distance( Point::new( 4.0, -2.0 ) ) - 5.0 ).abs() < EPSILON );
+        assert!( ( line.distance( Point::new( 3.0, 0.0 ) ) - 2.0_f64.sqrt() ).abs() < EPSILON );
+        assert_eq!( Point::new( 1.0, 2.0 ).distance( Point::ideal( 1.0, 0.0 ) ), f64::INFINITY );
+        assert_eq!( line.distance( Line::new( 1.0, 0.0, 0.0 ) ), 0.0 );
+        assert!( ( line.angle( Line::new( 1.0, 0.0, 0.0 ) ) - std::f64::consts::FRAC_PI_4 ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn distance_parallel() {
+        // Parallel lines 3 / √40 apart, the joins leave a sine of a few ulps between them
+        let lhs = Point::<f64>::new( 0.0, 0.0 ).join( Point::new( 0.03, 0.09 ) );
+        let rhs = Point::<f64>::new( 0.56, 0.18 ).join( Point::new( 0.59, 0.27 ) );
+
+        assert!( ( lhs.distance( rhs ) - 3.0 / 40.0_f64.sqrt() ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn project() {
+        let line = Line::<f64>::new( 1.0, 1.0, -1.0 );
+
+        assert_position( Point::new( 3.0, 0.0 ).project( line ).position(), [ 2.0, -1.0 ] );
+
+        let res = line.project( Point::from( *Point::new( 3.0, 0.0 ) * -2.0 ) );
+
+        assert!( res.distance( Point::new( 3.0, 0.0 ) ) < EPSILON );
+        assert!( res.angle( line ) < EPSILON );
+    }
+
+    #[test]
+    fn motor() {
+        let rotation = Motor2::from_rotation( Point::<f64>::new( 1.0, 2.0 ), std::f64::consts::FRAC_PI_2 );
+        let translation = Motor2::<f64>::from_translation( Vector::new([ 1.0, -2.0 ]) );
+
+        assert_position( rotation.rotate( Point::new( 2.0, 2.0 ) ).position(), [ 1.0, 3.0 ] );
+        assert_position( translation.rotate( Point::new( 1.0, 1.0 ) ).position(), [ 2.0, -1.0 ] );
+        assert_position( ( translation * rotation ).rotate( Point::new( 2.0, 2.0 ) ).position(), [ 2.0, 1.0 ] );
+        // x = 1 moves to x = 2
+        let line = translation.rotate( Line::new( 1.0, 0.0, -1.0 ) );
+        assert!( ( line[ 2 ] / line[ 0 ] + 2.0 ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn rotor2() {
+        let rotor = Rotor2::<f64>::from_angle( 1.1 );
+        let v = Vector::<f64, 2>::new([ 0.3, -1.2 ]);
+
+        let res = Motor2::from( rotor ).rotate( Point::new( v[ 0 ], v[ 1 ] ) );
+        let expected = rotor.rotate( v );
+
+        assert_position( res.position(), [ expected[ 0 ], expected[ 1 ] ] );
+    }
+
+    #[test]
+    fn exp_log() {
+        for point in [
+            Point::<f64>::from( BiVector::new([ 0.7, 0.3, -0.4 ]) ),
+            Point::from( BiVector::new([ 0.0, 0.3, -0.4 ]) )
+        ] {
+            let res = point.exp().log();
+
+            assert!( res.iter().zip( point.iter() ).all( |( a, b )| ( a - b ).abs() < EPSILON ) );
+        }
+    }
+
+    #[test]
+    fn signed_distance() {
+        let line = Line::<f64>::new( 0.0, -2.0, 4.0 );
+
+        assert!( ( Point::new( 5.0, 3.0 ).signed_distance( line ) + 1.0 ).abs() < EPSILON );
+        assert!( ( line.signed_distance( Point::from( *Point::new( 1.0, 0.0 ) * -3.0 ) ) - 2.0 ).abs() < EPSILON );
+        assert_eq!( Point::ideal( 1.0, 0.0 ).signed_distance( line ), f64::INFINITY );
+    }
+
+    #[test]
+    fn area() {
+        let [ o, x, y ] = [ [ 0.0, 0.0 ], [ 2.0, 0.0 ], [ 0.0, 3.0 ] ].map( |[ a, b ]| Point::<f64>::new( a, b ) );
+
+        assert!( ( super::area([ o, x, y ]).unwrap() - 3.0 ).abs() < EPSILON );
+        assert!( ( super::area([ o, Point::from( *x * -2.0 ), y ]).unwrap() - 3.0 ).abs() < EPSILON );
+        assert!( ( super::area([ o, y, x ]).unwrap() + 3.0 ).abs() < EPSILON );
+        assert!( super::area([ o, x, Point::new( 5.0, 0.0 ) ]).unwrap().abs() < EPSILON );
+        assert_eq!( super::area([ o, x, Point::ideal( 0.0, 1.0 ) ]), None );
+    }
+}
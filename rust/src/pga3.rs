@@ -0,0 +1,969 @@
+// Copyright 2024 Bewusstsein Labs
+
+use std::{
+    fmt::Debug,
+    ops::Deref
+};
+use num::traits::{ Num, Float, NumCast };
+
+use linear_algebra::{
+    ops::Normalize,
+    vector::Vector
+};
+
+use crate::{
+    ops::{
+        ExteriorProduct,
+        RegressiveProduct,
+        GeometricProduct,
+        Join,
+        Meet,
+        Project,
+        Reflect,
+        Distance,
+        SignedDistance,
+        Angle
+    },
+    metric::Projective3,
+    bivector::BiVector,
+    trivector::TriVector,
+    multivector::Multivector
+};
+
+/// A plane a x + b y + c z + d = 0 of 3D projective geometric algebra, the vector a e1 + b e2 + c e3 + d e0.
+///
+/// PGA3 is Cl(3, 0, 1), see [`Projective3`](crate::metric::Projective3), its degenerate basis vector e0 is stored
+/// last, as w. Planes are vectors, lines bivectors and points trivectors, so the exterior product meets and the
+/// regressive product joins. The plane d e0 with a = b = c = 0 is the ideal plane at infinity.
+///
+#[derive( Clone, Copy, Debug, Default, PartialEq )]
+pub struct Plane<T>( Vector<T, 4> )
+where
+    T: 'static + Default + Copy + Debug;
+
+/// A line of 3D projective geometric algebra, a bivector of PGA3 in the order xy, xz, xw, yz, yw, zw with w = e0.
+///
+/// In Plücker coordinates the line along v through p, with moment m = p × v, is
+/// v_x yz - v_y xz + v_z xy - m_x xw - m_y yw - m_z zw, the meet of two planes through it or the join of two points
+/// on it. Its Euclidean part carries the direction, its ideal part the moment. A line with a zero direction is an
+/// ideal line, lying in the plane at infinity.
+///
+#[derive( Clone, Copy, Debug, Default, PartialEq )]
+pub struct Line<T>( BiVector<T, 4> )
+where
+    T: 'static + Default + Copy + Debug;
+
+/// A point of 3D projective geometric algebra, a trivector of PGA3 in the order xyz, xyw, xzw, yzw with w = e0.
+///
+/// The point ( x, y, z ) is the meet of the planes through it parallel to the basis planes, xyz - z xyw + y xzw - x yzw,
+/// and any nonzero multiple of it. The xyz component is its weight, a point of weight zero is an ideal point, the
+/// direction shared by all lines parallel to ( x, y, z ).
+///
+#[derive( Clone, Copy, Debug, Default, PartialEq )]
+pub struct Point<T>( TriVector<T, 4> )
+where
+    T: 'static + Default + Copy + Debug;
+
+impl<T> Plane<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Creates the [`Plane`] a x + b y + c z + d = 0.
+    ///
+    pub fn new( a: T, b: T, c: T, d: T ) -> Self {
+        Self( Vector::new([ a, b, c, d ]) )
+    }
+
+    /// Returns the normal ( a, b, c ) of the [`Plane`].
+    ///
+    pub fn normal( &self ) -> Vector<T, 3> {
+        Vector::new([ self.0[ 0 ], self.0[ 1 ], self.0[ 2 ] ])
+    }
+
+    /// Returns the Euclidean norm of the [`Plane`], the length of its normal.
+    ///
+    pub fn euclidean_norm( &self ) -> T {
+        ( self.0[ 0 ] * self.0[ 0 ] + self.0[ 1 ] * self.0[ 1 ] + self.0[ 2 ] * self.0[ 2 ] ).sqrt()
+    }
+
+    /// Returns the ideal norm of the [`Plane`], |d|, its distance from the origin once normalized.
+    ///
+    pub fn ideal_norm( &self ) -> T {
+        self.0[ 3 ].abs()
+    }
+
+    /// Returns `true` if the Euclidean norm of the [`Plane`] exceeds `epsilon`, i.e. if it is a finite plane.
+    ///
+    pub fn is_euclidean( &self, epsilon: T ) -> bool {
+        self.euclidean_norm() > epsilon
+    }
+
+    /// Returns `true` if the [`Plane`] is the plane at infinity: its Euclidean norm is at most `epsilon`, its ideal
+    /// norm is not.
+    ///
+    pub fn is_ideal( &self, epsilon: T ) -> bool {
+        !self.is_euclidean( epsilon ) && self.ideal_norm() > epsilon
+    }
+}
+
+impl<T> Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Creates the [`Line`] with the Plücker coordinates `direction` and `moment`.
+    ///
+    /// The line along v through p has the moment m = p × v, a zero direction gives an ideal line.
+    ///
+    pub fn new( direction: Vector<T, 3>, moment: Vector<T, 3> ) -> Self {
+        Self( BiVector::new([ direction[ 2 ], -direction[ 1 ], -moment[ 0 ], direction[ 0 ], -moment[ 1 ], -moment[ 2 ] ]) )
+    }
+
+    /// Returns the direction of the [`Line`], its Euclidean part.
+    ///
+    pub fn direction( &self ) -> Vector<T, 3> {
+        Vector::new([ self.0[ 3 ], -self.0[ 1 ], self.0[ 0 ] ])
+    }
+
+    /// Returns the moment of the [`Line`], its ideal part, p × v for any point p on it.
+    ///
+    pub fn moment( &self ) -> Vector<T, 3> {
+        Vector::new([ -self.0[ 2 ], -self.0[ 4 ], -self.0[ 5 ] ])
+    }
+
+    /// Returns the Euclidean norm of the [`Line`], the length of its direction.
+    ///
+    pub fn euclidean_norm( &self ) -> T {
+        ( self.0[ 0 ] * self.0[ 0 ] + self.0[ 1 ] * self.0[ 1 ] + self.0[ 3 ] * self.0[ 3 ] ).sqrt()
+    }
+
+    /// Returns the ideal norm of the [`Line`], the length of its moment, its distance from the origin once normalized.
+    ///
+    pub fn ideal_norm( &self ) -> T {
+        ( self.0[ 2 ] * self.0[ 2 ] + self.0[ 4 ] * self.0[ 4 ] + self.0[ 5 ] * self.0[ 5 ] ).sqrt()
+    }
+
+    /// Returns `true` if the Euclidean norm of the [`Line`] exceeds `epsilon`, i.e. if it is a finite line.
+    ///
+    pub fn is_euclidean( &self, epsilon: T ) -> bool {
+        self.euclidean_norm() > epsilon
+    }
+
+    /// Returns `true` if the [`Line`] lies in the plane at infinity: its Euclidean norm is at most `epsilon`, its
+    /// ideal norm is not.
+    ///
+    pub fn is_ideal( &self, epsilon: T ) -> bool {
+        !self.is_euclidean( epsilon ) && self.ideal_norm() > epsilon
+    }
+}
+
+impl<T> Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Creates the [`Point`] ( x, y, z ) of weight 1.
+    ///
+    pub fn new( x: T, y: T, z: T ) -> Self {
+        Self( TriVector::new([ T::one(), -z, y, -x ]) )
+    }
+
+    /// Creates the ideal [`Point`] in the direction ( x, y, z ).
+    ///
+    pub fn ideal( x: T, y: T, z: T ) -> Self {
+        Self( TriVector::new([ T::zero(), -z, y, -x ]) )
+    }
+
+    /// Returns the weight of the [`Point`], its xyz component.
+    ///
+    pub fn weight( &self ) -> T {
+        self.0[ 0 ]
+    }
+
+    /// Returns the x, y, z part of the [`Point`], its position times its weight, or its direction if it is ideal.
+    ///
+    pub fn direction( &self ) -> Vector<T, 3> {
+        Vector::new([ -self.0[ 3 ], self.0[ 2 ], -self.0[ 1 ] ])
+    }
+
+    /// Returns the position of the [`Point`], or `None` if it is ideal.
+    ///
+    pub fn position( &self ) -> Option<Vector<T, 3>> {
+        let weight = self.weight();
+        if weight == T::zero() {
+            return None;
+        }
+        Some( self.direction() * ( T::one() / weight ) )
+    }
+
+    /// Returns the Euclidean norm of the [`Point`], the absolute value of its weight.
+    ///
+    pub fn euclidean_norm( &self ) -> T {
+        self.0[ 0 ].abs()
+    }
+
+    /// Returns the ideal norm of the [`Point`], the length of its x, y, z part.
+    ///
+    pub fn ideal_norm( &self ) -> T {
+        ( self.0[ 1 ] * self.0[ 1 ] + self.0[ 2 ] * self.0[ 2 ] + self.0[ 3 ] * self.0[ 3 ] ).sqrt()
+    }
+
+    /// Returns `true` if the Euclidean norm of the [`Point`] exceeds `epsilon`, i.e. if it is a finite point.
+    ///
+    pub fn is_euclidean( &self, epsilon: T ) -> bool {
+        self.euclidean_norm() > epsilon
+    }
+
+    /// Returns `true` if the [`Point`] is a direction: its weight is at most `epsilon`, its ideal norm is not.
+    ///
+    pub fn is_ideal( &self, epsilon: T ) -> bool {
+        !self.is_euclidean( epsilon ) && self.ideal_norm() > epsilon
+    }
+}
+
+impl<T> Deref for Plane<T>
+where
+    T: 'static + Copy + Default + Debug
+{
+    type Target = Vector<T, 4>;
+
+    fn deref( &self ) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Deref for Line<T>
+where
+    T: 'static + Copy + Default + Debug
+{
+    type Target = BiVector<T, 4>;
+
+    fn deref( &self ) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Deref for Point<T>
+where
+    T: 'static + Copy + Default + Debug
+{
+    type Target = TriVector<T, 4>;
+
+    fn deref( &self ) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<Vector<T, 4>> for Plane<T>
+where
+    T: 'static + Copy + Default + Debug
+{
+    fn from( src: Vector<T, 4> ) -> Self {
+        Self( src )
+    }
+}
+
+impl<T> From<Plane<T>> for Vector<T, 4>
+where
+    T: 'static + Copy + Default + Debug
+{
+    fn from( src: Plane<T> ) -> Self {
+        src.0
+    }
+}
+
+impl<T> From<BiVector<T, 4>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug
+{
+    fn from( src: BiVector<T, 4> ) -> Self {
+        Self( src )
+    }
+}
+
+impl<T> From<Line<T>> for BiVector<T, 4>
+where
+    T: 'static + Copy + Default + Debug
+{
+    fn from( src: Line<T> ) -> Self {
+        src.0
+    }
+}
+
+impl<T> From<TriVector<T, 4>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug
+{
+    fn from( src: TriVector<T, 4> ) -> Self {
+        Self( src )
+    }
+}
+
+impl<T> From<Point<T>> for TriVector<T, 4>
+where
+    T: 'static + Copy + Default + Debug
+{
+    fn from( src: Point<T> ) -> Self {
+        src.0
+    }
+}
+
+/// Scales the [`Plane`] to a unit normal, or an ideal plane to d = ±1. A zero plane stays zero.
+///
+impl<T> Normalize for Plane<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Self;
+
+    fn normalize( self ) -> Self::Output {
+        let norm = if self.euclidean_norm() > T::zero() { self.euclidean_norm() } else { self.ideal_norm() };
+        if norm == T::zero() {
+            return self;
+        }
+        Self( self.0 * ( T::one() / norm ) )
+    }
+}
+
+/// Scales the [`Line`] to a unit direction, or an ideal line to a unit moment. A zero line stays zero.
+///
+impl<T> Normalize for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Self;
+
+    fn normalize( self ) -> Self::Output {
+        let norm = if self.euclidean_norm() > T::zero() { self.euclidean_norm() } else { self.ideal_norm() };
+        if norm == T::zero() {
+            return self;
+        }
+        Self( self.0 * ( T::one() / norm ) )
+    }
+}
+
+/// Scales the [`Point`] to weight 1, or an ideal point to a unit direction. A zero point stays zero.
+///
+impl<T> Normalize for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Self;
+
+    fn normalize( self ) -> Self::Output {
+        let norm = if self.weight() != T::zero() { self.weight() } else { self.ideal_norm() };
+        if norm == T::zero() {
+            return self;
+        }
+        Self( self.0 * ( T::one() / norm ) )
+    }
+}
+
+/// P ∨ Q, the line through both points, running from P to Q.
+///
+impl<T> Join<Point<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Line<T>;
+
+    fn join( self, rhs: Point<T> ) -> Self::Output {
+        Line::from( self.0.regressive_product( rhs.0 ) )
+    }
+}
+
+/// L ∨ P, the plane through the line and the point, zero if the point lies on the line.
+///
+impl<T> Join<Point<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Plane<T>;
+
+    fn join( self, rhs: Point<T> ) -> Self::Output {
+        Plane::from( Vector::from( self.0.regressive_product( rhs.0 ) ) )
+    }
+}
+
+/// p ∧ q, the line both planes pass through, or their common ideal line if they are parallel.
+///
+impl<T> Meet<Plane<T>> for Plane<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Line<T>;
+
+    fn meet( self, rhs: Plane<T> ) -> Self::Output {
+        Line::from( self.0.exterior_product( rhs.0 ) )
+    }
+}
+
+/// L ∧ p, the point the line passes through the plane in, or the direction of the line if they are parallel.
+///
+impl<T> Meet<Plane<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Point<T>;
+
+    fn meet( self, rhs: Plane<T> ) -> Self::Output {
+        Point::from( self.0.exterior_product( rhs.0 ) )
+    }
+}
+
+/// ( P ∨ N ) ∧ p, the foot of the perpendicular from the point onto the plane, with N the ideal point in the direction
+/// of its normal.
+///
+impl<T> Project<Plane<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Point<T>;
+
+    fn project( self, rhs: Plane<T> ) -> Self::Output {
+        let normal = rhs.normal();
+        self.join( Point::ideal( normal[ 0 ], normal[ 1 ], normal[ 2 ] ) ).meet( rhs )
+    }
+}
+
+/// L ∧ ( N ∨ P ), the foot of the perpendicular from the point onto the line, with N the ideal line of the planes
+/// normal to it.
+///
+impl<T> Project<Line<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Point<T>;
+
+    fn project( self, rhs: Line<T> ) -> Self::Output {
+        let direction = rhs.direction();
+        let normal = Plane::new( direction[ 0 ], direction[ 1 ], direction[ 2 ], T::zero() )
+            .meet( Plane::new( T::zero(), T::zero(), T::zero(), T::one() ) );
+        rhs.meet( normal.join( self ) )
+    }
+}
+
+/// Returns p X p / |n|² for the plane p with normal n, the reflection of X in it up to the sign of each grade, or
+/// `None` if p is the plane at infinity, which has no normal to reflect along.
+///
+fn sandwich<T>( plane: Plane<T>, x: Multivector<T, 4, Projective3> ) -> Option<Multivector<T, 4, Projective3>>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    let norm = plane.euclidean_norm();
+    if norm == T::zero() {
+        return None;
+    }
+    let plane = Multivector::<T, 4, Projective3>::from( plane.0 );
+    Some( plane.geometric_product( x ).geometric_product( plane ) / ( norm * norm ) )
+}
+
+/// -p X p, the mirror image of the plane in the finite plane p, its normal reflected as well. The plane at infinity
+/// leaves it unchanged.
+///
+impl<T> Reflect<Plane<T>> for Plane<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Plane<T>;
+
+    fn reflect( self, rhs: Plane<T> ) -> Self::Output {
+        sandwich( rhs, Multivector::from( self.0 ) ).map_or( self, |res| Plane::from( ( -res ).vector() ) )
+    }
+}
+
+/// -p L p, the mirror image of the line in the finite plane p, its direction reflected as well. The plane at infinity
+/// leaves it unchanged.
+///
+impl<T> Reflect<Plane<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Line<T>;
+
+    fn reflect( self, rhs: Plane<T> ) -> Self::Output {
+        sandwich( rhs, Multivector::from( self.0 ) ).map_or( self, |res| Line::from( ( -res ).kvector::<2>() ) )
+    }
+}
+
+/// p P p, the mirror image of the point in the finite plane p, keeping its weight. The plane at infinity leaves it
+/// unchanged.
+///
+impl<T> Reflect<Plane<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = Point<T>;
+
+    fn reflect( self, rhs: Plane<T> ) -> Self::Output {
+        sandwich( rhs, Multivector::from( self.0 ) ).map_or( self, |res| Point::from( res.kvector::<3>() ) )
+    }
+}
+
+/// Returns the oriented volume of the tetrahedron on the four `points`, or `None` if one of them is ideal.
+///
+/// -( P₀ ∨ P₁ ∨ P₂ ∨ P₃ ) / 6 of the normalized points, positive if P₁ - P₀, P₂ - P₀, P₃ - P₀ are right-handed and
+/// zero if the points are coplanar.
+///
+pub fn volume<T>( points: [ Point<T>; 4 ] ) -> Option<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    if points.iter().any( |point| point.weight() == T::zero() ) {
+        return None;
+    }
+    let [ a, b, c, d ] = points.map( |point| point.normalize() );
+    let six = <T as NumCast>::from( 6 ).unwrap();
+    Some( -a.join( b ).join( c ).0.regressive_product( d.0 )[ 0 ] / six )
+}
+
+/// Returns |a × b| and a · b, the sine and cosine of the angle between `a` and `b` times their lengths.
+///
+fn sin_cos<T>( a: Vector<T, 3>, b: Vector<T, 3> ) -> ( T, T )
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    let sin = a.exterior_product( b ).iter().fold( T::zero(), |acc, &c| acc + c * c ).sqrt();
+    ( sin, ( 0..3 ).fold( T::zero(), |acc, i| acc + a[ i ] * b[ i ] ) )
+}
+
+/// |P̂ ∨ Q̂|, infinite if either point is ideal.
+///
+impl<T> Distance<Point<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Point<T> ) -> Self::Output {
+        if self.weight() == T::zero() || rhs.weight() == T::zero() {
+            return T::infinity();
+        }
+        self.normalize().join( rhs.normalize() ).euclidean_norm()
+    }
+}
+
+/// |L̂ ∨ P̂|, the normal of the plane through both, infinite if the point or the line is ideal.
+///
+impl<T> Distance<Line<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Line<T> ) -> Self::Output {
+        if self.weight() == T::zero() || rhs.euclidean_norm() == T::zero() {
+            return T::infinity();
+        }
+        rhs.normalize().join( self.normalize() ).euclidean_norm()
+    }
+}
+
+/// |L̂ ∨ P̂|, the normal of the plane through both, infinite if the point or the line is ideal.
+///
+impl<T> Distance<Point<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Point<T> ) -> Self::Output {
+        rhs.distance( self )
+    }
+}
+
+/// |p̂ ∧ P̂|, infinite if the point or the plane is ideal.
+///
+impl<T> Distance<Plane<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Plane<T> ) -> Self::Output {
+        self.signed_distance( rhs ).abs()
+    }
+}
+
+/// |p̂ ∧ P̂|, infinite if the point or the plane is ideal.
+///
+impl<T> Distance<Point<T>> for Plane<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Point<T> ) -> Self::Output {
+        rhs.distance( self )
+    }
+}
+
+/// |L̂ ∨ M̂| / |v × w| for skew lines with the unit directions v and w, zero for crossing lines. Parallel lines
+/// differ by an ideal line, L̂ ∓ M̂, whose ideal norm is the distance between them. Infinite if either line is ideal.
+///
+/// Lines count as parallel if the sine between their unit directions is at most √ε.
+///
+impl<T> Distance<Line<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Line<T> ) -> Self::Output {
+        if self.euclidean_norm() == T::zero() || rhs.euclidean_norm() == T::zero() {
+            return T::infinity();
+        }
+        let ( l, m ) = ( self.normalize(), rhs.normalize() );
+        let ( sin, cos ) = sin_cos( l.direction(), m.direction() );
+        if sin > T::epsilon().sqrt() {
+            return l.0.regressive_product( m.0 )[ 0 ].abs() / sin;
+        }
+        Line::from( l.0 + m.0 * -cos.signum() ).ideal_norm()
+    }
+}
+
+/// Zero unless the line is parallel to the plane, then they meet in an ideal point whose ideal norm, once both are
+/// normalized, is the distance between them. Infinite if either is ideal. Parallel means that the cosine between the
+/// unit direction and the unit normal is at most √ε.
+///
+impl<T> Distance<Plane<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Plane<T> ) -> Self::Output {
+        if self.euclidean_norm() == T::zero() || rhs.euclidean_norm() == T::zero() {
+            return T::infinity();
+        }
+        let ( l, p ) = ( self.normalize(), rhs.normalize() );
+        if sin_cos( l.direction(), p.normal() ).1.abs() > T::epsilon().sqrt() {
+            return T::zero();
+        }
+        l.meet( p ).ideal_norm()
+    }
+}
+
+/// Zero unless the line is parallel to the plane, then they meet in an ideal point whose ideal norm, once both are
+/// normalized, is the distance between them. Infinite if either is ideal.
+///
+impl<T> Distance<Line<T>> for Plane<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Line<T> ) -> Self::Output {
+        rhs.distance( self )
+    }
+}
+
+/// Zero for crossing planes. Parallel planes meet in an ideal line whose ideal norm, once both are normalized, is
+/// the distance between them, planes whose unit normals are within a sine of √ε count as parallel. Infinite if either
+/// plane is ideal.
+///
+impl<T> Distance<Plane<T>> for Plane<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn distance( self, rhs: Plane<T> ) -> Self::Output {
+        if self.euclidean_norm() == T::zero() || rhs.euclidean_norm() == T::zero() {
+            return T::infinity();
+        }
+        let meet = self.normalize().meet( rhs.normalize() );
+        // The Euclidean norm of the meet is the sine of the angle between the planes
+        if meet.euclidean_norm() > T::epsilon().sqrt() {
+            return T::zero();
+        }
+        meet.ideal_norm()
+    }
+}
+
+/// -( p ∧ P ) / ( |n| w ) = ( a x + b y + c z + d ) / |n| for the plane p with normal n = ( a, b, c ) and the point
+/// P = w ( x, y, z ), infinite if either is ideal.
+///
+impl<T> SignedDistance<Plane<T>> for Point<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn signed_distance( self, rhs: Plane<T> ) -> Self::Output {
+        let norm = self.weight() * rhs.euclidean_norm();
+        if norm == T::zero() {
+            return T::infinity();
+        }
+        -rhs.0.exterior_product( self.0 )[ 0 ] / norm
+    }
+}
+
+/// -( p ∧ P ) / ( |n| w ) = ( a x + b y + c z + d ) / |n| for the plane p with normal n = ( a, b, c ) and the point
+/// P = w ( x, y, z ), infinite if either is ideal.
+///
+impl<T> SignedDistance<Point<T>> for Plane<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn signed_distance( self, rhs: Point<T> ) -> Self::Output {
+        rhs.signed_distance( self )
+    }
+}
+
+/// The angle between the directions of the lines, atan2( |v × w|, v · w ).
+///
+impl<T> Angle<Line<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn angle( self, rhs: Line<T> ) -> Self::Output {
+        let ( sin, cos ) = sin_cos( self.direction(), rhs.direction() );
+        sin.atan2( cos )
+    }
+}
+
+/// The angle between the normals of the planes, atan2( |n × m|, n · m ).
+///
+impl<T> Angle<Plane<T>> for Plane<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn angle( self, rhs: Plane<T> ) -> Self::Output {
+        let ( sin, cos ) = sin_cos( self.normal(), rhs.normal() );
+        sin.atan2( cos )
+    }
+}
+
+/// The angle between the line and its projection onto the plane, atan2( |v · n|, |v × n| ), in [ 0, π / 2 ].
+///
+impl<T> Angle<Plane<T>> for Line<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn angle( self, rhs: Plane<T> ) -> Self::Output {
+        let ( sin, cos ) = sin_cos( self.direction(), rhs.normal() );
+        cos.abs().atan2( sin )
+    }
+}
+
+/// The angle between the line and its projection onto the plane, atan2( |v · n|, |v × n| ), in [ 0, π / 2 ].
+///
+impl<T> Angle<Line<T>> for Plane<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Output = T;
+
+    fn angle( self, rhs: Line<T> ) -> Self::Output {
+        rhs.angle( self )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{ EPSILON, assert_position };
+
+    fn assert_on( plane: Plane<f64>, point: [ f64; 3 ] ) {
+        assert!( ( plane[ 0 ] * point[ 0 ] + plane[ 1 ] * point[ 1 ] + plane[ 2 ] * point[ 2 ] + plane[ 3 ] ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn plane() {
+        let plane = Plane::<f64>::new( 0.0, 3.0, 4.0, -10.0 );
+
+        let res = plane.normalize();
+
+        assert_eq!( plane.euclidean_norm(), 5.0 );
+        assert!( ( res.normal()[ 1 ] - 0.6 ).abs() < EPSILON );
+        assert!( ( res.normal()[ 2 ] - 0.8 ).abs() < EPSILON );
+        assert!( ( res.ideal_norm() - 2.0 ).abs() < EPSILON );
+        assert!( plane.is_euclidean( EPSILON ) && !plane.is_ideal( EPSILON ) );
+        assert!( Plane::<f64>::new( 0.0, 0.0, 0.0, 2.0 ).is_ideal( EPSILON ) );
+        assert_eq!( Plane::<f64>::new( 0.0, 0.0, 0.0, -2.0 ).normalize()[ 3 ], -1.0 );
+    }
+
+    #[test]
+    fn line() {
+        // Along z through ( 1, 0, 0 ), m = p × v = ( 0, -1, 0 )
+        let line = Line::<f64>::new( Vector::new([ 0.0, 0.0, 2.0 ]), Vector::new([ 0.0, -2.0, 0.0 ]) );
+
+        let res = line.normalize();
+
+        assert_eq!( res.direction(), Vector::new([ 0.0, 0.0, 1.0 ]) );
+        assert_eq!( res.moment(), Vector::new([ 0.0, -1.0, 0.0 ]) );
+        assert_eq!( res.ideal_norm(), 1.0 );
+        assert!( line.is_euclidean( EPSILON ) && !line.is_ideal( EPSILON ) );
+        assert!( Line::<f64>::new( Vector::default(), Vector::new([ 1.0, 0.0, 0.0 ]) ).is_ideal( EPSILON ) );
+    }
+
+    #[test]
+    fn point() {
+        let point = Point::<f64>::new( 1.0, -2.0, 3.0 );
+        let scaled = Point::from( *point * -2.0 );
+
+        assert_eq!( scaled.position(), Some( Vector::new([ 1.0, -2.0, 3.0 ]) ) );
+        assert_eq!( scaled.normalize(), point );
+        assert!( point.is_euclidean( EPSILON ) && !point.is_ideal( EPSILON ) );
+
+        let ideal = Point::<f64>::ideal( 0.0, 3.0, 4.0 );
+
+        assert_eq!( ideal.position(), None );
+        assert!( ideal.is_ideal( EPSILON ) );
+        assert!( ( ideal.normalize().direction()[ 2 ] - 0.8 ).abs() < EPSILON );
+        assert!( !Point::<f64>::default().is_euclidean( EPSILON ) && !Point::<f64>::default().is_ideal( EPSILON ) );
+    }
+
+    #[test]
+    fn join() {
+        let line = Point::<f64>::new( 1.0, 0.0, 0.0 ).join( Point::new( 1.0, 0.0, 2.0 ) );
+
+        assert_eq!( line.direction(), Vector::new([ 0.0, 0.0, 2.0 ]) );
+        assert_eq!( line.moment(), Vector::new([ 0.0, -2.0, 0.0 ]) );
+
+        // The plane through the line along ( 0, 1, 1 ) through ( 1, 2, 3 ) and the origin, normal ∥ ( -1, -1, 1 )
+        let line = Point::<f64>::new( 1.0, 2.0, 3.0 ).join( Point::new( 1.0, 3.0, 4.0 ) );
+        let res = line.join( Point::new( 0.0, 0.0, 0.0 ) ).normalize();
+
+        assert_on( res, [ 0.0, 0.0, 0.0 ] );
+        assert_on( res, [ 1.0, 2.0, 3.0 ] );
+        assert_on( res, [ 1.0, 3.0, 4.0 ] );
+        assert!( ( res[ 0 ] - res[ 1 ] ).abs() < EPSILON && ( res[ 0 ] + res[ 2 ] ).abs() < EPSILON );
+        assert_eq!( line.join( Point::new( 1.0, 4.0, 5.0 ) ), Plane::default() );
+    }
+
+    #[test]
+    fn meet() {
+        let line = Plane::<f64>::new( 1.0, 0.0, 0.0, -1.0 ).meet( Plane::new( 0.0, 1.0, 0.0, -2.0 ) );
+
+        assert_eq!( line.direction(), Vector::new([ 0.0, 0.0, 1.0 ]) );
+        assert_eq!( line.moment(), Vector::new([ 2.0, -1.0, 0.0 ]) );
+        assert_position( line.meet( Plane::new( 0.0, 0.0, 1.0, -3.0 ) ).position(), [ 1.0, 2.0, 3.0 ] );
+
+        // Parallel planes meet at infinity, a line parallel to a plane in its direction
+        let plane = Plane::<f64>::new( 0.0, 0.0, 2.0, 1.0 );
+
+        assert!( Plane::new( 0.0, 0.0, 1.0, -3.0 ).meet( plane ).is_ideal( EPSILON ) );
+        let res = Plane::new( 0.0, 1.0, 0.0, 0.0 ).meet( Plane::new( 1.0, 0.0, 0.0, -1.0 ) ).meet( Plane::new( 1.0, 0.0, 0.0, 0.0 ) );
+        assert!( res.is_ideal( EPSILON ) );
+        assert_eq!( res.normalize().direction()[ 2 ].abs(), 1.0 );
+    }
+
+    #[test]
+    fn project() {
+        // ( 4, 1, 0 ) - ( 4 + 2 - 3 ) / 9 ( 1, 2, 2 )
+        assert_position( Point::new( 4.0, 1.0, 0.0 ).project( Plane::new( 1.0, 2.0, 2.0, -3.0 ) ).position(), [ 11.0 / 3.0, 1.0 / 3.0, -2.0 / 3.0 ] );
+
+        let line = Point::<f64>::new( 0.0, 0.0, 0.0 ).join( Point::new( 1.0, 1.0, 0.0 ) );
+
+        assert_position( Point::new( 2.0, 0.0, 5.0 ).project( line ).position(), [ 1.0, 1.0, 0.0 ] );
+        assert_position( Point::new( -3.0, -3.0, 0.0 ).project( line ).position(), [ -3.0, -3.0, 0.0 ] );
+    }
+
+    #[test]
+    fn reflect() {
+        let mirror = Plane::<f64>::new( 2.0, 0.0, 0.0, -2.0 );
+
+        let point = Point::new( 3.0, 2.0, 1.0 ).reflect( mirror );
+        assert_position( point.position(), [ -1.0, 2.0, 1.0 ] );
+        assert!( ( point.weight() - 1.0 ).abs() < EPSILON );
+        let ideal = Point::ideal( 1.0, 2.0, 0.0 ).reflect( mirror );
+        assert!( ( ideal.direction()[ 0 ] + 1.0 ).abs() < EPSILON && ( ideal.direction()[ 1 ] - 2.0 ).abs() < EPSILON );
+
+        // x + y = 3 mirrors to -x + y = 1
+        let plane = Plane::new( 1.0, 1.0, 0.0, -3.0 ).reflect( mirror );
+        assert!( [ -1.0, 1.0, 0.0, -1.0 ].iter().enumerate().all( |( i, a )| ( plane[ i ] - a ).abs() < EPSILON ) );
+
+        // The z parallel through ( 2, 0, 0 ) mirrors to the z axis
+        let line = Line::new( Vector::new([ 0.0, 0.0, 1.0 ]), Vector::new([ 0.0, -2.0, 0.0 ]) ).reflect( mirror );
+        assert!( ( line.direction()[ 2 ] - 1.0 ).abs() < EPSILON );
+        assert!( line.ideal_norm() < EPSILON );
+    }
+
+    #[test]
+    fn reflect_ideal() {
+        let infinity = Plane::<f64>::new( 0.0, 0.0, 0.0, 1.0 );
+        let point = Point::new( 3.0, 2.0, 1.0 );
+        let plane = Plane::new( 1.0, 1.0, 0.0, -3.0 );
+        let line = Line::new( Vector::new([ 0.0, 0.0, 1.0 ]), Vector::new([ 0.0, -2.0, 0.0 ]) );
+
+        assert_eq!( point.reflect( infinity ), point );
+        assert_eq!( plane.reflect( infinity ), plane );
+        assert_eq!( line.reflect( infinity ), line );
+    }
+
+    #[test]
+    fn distance() {
+        let ( p, q ) = ( Point::<f64>::new( 1.0, 2.0, 3.0 ), Point::new( 3.0, 5.0, 9.0 ) );
+        let z = Line::new( Vector::new([ 0.0, 0.0, 2.0 ]), Vector::default() );
+
+        assert!( ( p.distance( q ) - 7.0 ).abs() < EPSILON );
+        assert!( ( Point::new( 3.0, 4.0, 7.0 ).distance( z ) - 5.0 ).abs() < EPSILON );
+        assert!( ( Plane::new( 0.0, 0.0, -2.0, 4.0 ).distance( p ) - 1.0 ).abs() < EPSILON );
+        assert_eq!( p.distance( Point::ideal( 1.0, 0.0, 0.0 ) ), f64::INFINITY );
+
+        // The x parallel through ( 0, 5, 0 ) and the diagonal through it pass the z axis at 5
+        let x = Point::new( 0.0, 5.0, 0.0 ).join( Point::new( 1.0, 5.0, 0.0 ) );
+        let diagonal = Point::new( 0.0, 5.0, 0.0 ).join( Point::new( 1.0, 5.0, 1.0 ) );
+        assert!( ( z.distance( x ) - 5.0 ).abs() < EPSILON );
+        assert!( ( diagonal.distance( z ) - 5.0 ).abs() < EPSILON );
+        assert!( x.distance( diagonal ) < EPSILON );
+    }
+
+    #[test]
+    fn distance_parallel() {
+        let z = Line::<f64>::new( Vector::new([ 0.0, 0.0, 1.0 ]), Vector::default() );
+        // Along -z through ( 3, 4, 0 ), m = p × v = ( -4, 3, 0 ) · 2
+        let line = Line::new( Vector::new([ 0.0, 0.0, -2.0 ]), Vector::new([ -8.0, 6.0, 0.0 ]) );
+
+        assert!( ( z.distance( line ) - 5.0 ).abs() < EPSILON );
+        assert!( ( line.distance( Plane::new( 0.0, 3.0, 0.0, 3.0 ) ) - 5.0 ).abs() < EPSILON );
+        assert_eq!( line.distance( Plane::new( 0.0, 0.0, 1.0, 3.0 ) ), 0.0 );
+        assert!( ( Plane::new( 0.0, 0.0, 1.0, -2.0 ).distance( Plane::new( 0.0, 0.0, -3.0, -3.0 ) ) - 3.0 ).abs() < EPSILON );
+        assert_eq!( Plane::new( 0.0, 0.0, 1.0, -2.0 ).distance( Plane::new( 1.0, 0.0, 0.0, 0.0 ) ), 0.0 );
+
+        // Joins of points translated by t leave a sine of a few ulps between the parallel results
+        let t = |x: f64, y: f64, z: f64| Point::new( x + 0.56, y + 0.18, z + 0.2 );
+        let ( a, b, c ) = ( Point::<f64>::new( 0.0, 0.0, 0.0 ), Point::new( 0.01, 0.03, 0.0 ), Point::new( 0.0, 0.03, 0.07 ) );
+        let ( a_t, b_t, c_t ) = ( t( 0.0, 0.0, 0.0 ), t( 0.01, 0.03, 0.0 ), t( 0.0, 0.03, 0.07 ) );
+        assert!( ( a.join( b ).distance( a_t.join( b_t ) ) - 0.265_f64.sqrt() ).abs() < EPSILON );
+        assert!( ( a.join( b ).join( c ).distance( a_t.join( b_t ).join( c_t ) ) - 0.111 / 0.0499_f64.sqrt() ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn signed_distance() {
+        let plane = Plane::<f64>::new( 0.0, 0.0, 2.0, -4.0 );
+
+        assert!( ( Point::new( 0.0, 0.0, 5.0 ).signed_distance( plane ) - 3.0 ).abs() < EPSILON );
+        assert!( ( plane.signed_distance( Point::from( *Point::new( 1.0, 1.0, 0.0 ) * -2.0 ) ) + 2.0 ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn angle() {
+        let x = Line::<f64>::new( Vector::new([ 1.0, 0.0, 0.0 ]), Vector::default() );
+        let diagonal = Line::new( Vector::new([ -1.0, 1.0, 0.0 ]), Vector::new([ 0.0, 0.0, 5.0 ]) );
+
+        assert!( ( x.angle( diagonal ) - 3.0 * std::f64::consts::FRAC_PI_4 ).abs() < EPSILON );
+        assert!( ( Plane::new( 1.0, 0.0, 0.0, 0.0 ).angle( Plane::new( 0.0, 2.0, 0.0, 1.0 ) ) - std::f64::consts::FRAC_PI_2 ).abs() < EPSILON );
+        assert!( ( diagonal.angle( Plane::new( 0.0, 0.0, 1.0, 0.0 ) ) ).abs() < EPSILON );
+        assert!( ( diagonal.angle( Plane::new( 0.0, 1.0, 0.0, 0.0 ) ) - std::f64::consts::FRAC_PI_4 ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn volume() {
+        let [ o, x, y, z ] = [ [ 0.0, 0.0, 0.0 ], [ 1.0, 0.0, 0.0 ], [ 0.0, 1.0, 0.0 ], [ 0.0, 0.0, 1.0 ] ]
+            .map( |[ a, b, c ]| Point::<f64>::new( a, b, c ) );
+
+        assert!( ( super::volume([ o, x, y, z ]).unwrap() - 1.0 / 6.0 ).abs() < EPSILON );
+        assert!( ( super::volume([ o, y, x, Point::from( *z * 3.0 ) ]).unwrap() + 1.0 / 6.0 ).abs() < EPSILON );
+        assert!( super::volume([ o, x, y, Point::new( 1.0, 1.0, 0.0 ) ]).unwrap().abs() < EPSILON );
+        assert_eq!( super::volume([ o, x, y, Point::ideal( 0.0, 0.0, 1.0 ) ]), None );
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2024 Bewusstsein Labs
+
+use std::{
+    fmt::Debug,
+    ops::Neg
+};
+use num::traits::Num;
+
+use crate::{
+    blade::{
+        self,
+        binomial
+    },
+    kvector::KVector
+};
+
+/// A pseudoscalar type of generic element and size.
+///
+/// The grade DIM [`KVector`], it has a single component, the coefficient of I = e_1 ∧ ... ∧ e_DIM.
+/// Like the other k-vectors it assumes the Euclidean metric.
+///
+pub type Pseudoscalar<T, const DIM: usize> = KVector<T, DIM, DIM>;
+
+impl<T, const DIM: usize> Pseudoscalar<T, DIM>
+where
+    T: 'static + Copy + Default + Debug + Num + Neg<Output = T>,
+    [(); binomial( DIM, DIM )]:
+{
+    /// Returns the unit [`Pseudoscalar`] I.
+    ///
+    pub fn unit() -> Self {
+        let mut res = Self::default();
+        res[ 0 ] = T::one();
+        res
+    }
+
+    /// Returns the Euclidean square of the [`Pseudoscalar`], I² = (-1)^(DIM (DIM - 1) / 2) for the unit pseudoscalar.
+    ///
+    pub fn square( &self ) -> T {
+        let res = self[ 0 ] * self[ 0 ];
+        if blade::reverse_negates( DIM ) {
+            -res
+        } else {
+            res
+        }
+    }
+
+    /// Returns the Euclidean inverse of the [`Pseudoscalar`], I⁻¹ = Ĩ / |I|².
+    ///
+    pub fn inverse( &self ) -> Self {
+        let mut res = Self::default();
+        res[ 0 ] = if blade::reverse_negates( DIM ) {
+            -( T::one() / self[ 0 ] )
+        } else {
+            T::one() / self[ 0 ]
+        };
+        res
+    }
+}
+
+pub type Pseudoscalar2<T> = Pseudoscalar<T, 2>;
+pub type Pseudoscalar3<T> = Pseudoscalar<T, 3>;
+pub type Pseudoscalar4<T> = Pseudoscalar<T, 4>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square() {
+        assert_eq!( Pseudoscalar2::<f64>::unit().square(), -1.0 );
+        assert_eq!( Pseudoscalar3::<f64>::unit().square(), -1.0 );
+        assert_eq!( Pseudoscalar4::<f64>::unit().square(), 1.0 );
+    }
+
+    #[test]
+    fn inverse_3() {
+        let mut i = Pseudoscalar3::<f64>::unit();
+        i[ 0 ] = 2.0;
+
+        assert_eq!( i.inverse()[ 0 ], -0.5 );
+    }
+}
@@ -0,0 +1,13 @@
+// Copyright 2024 Bewusstsein Labs
+
+use crate::kvector::KVector;
+
+/// A quadvector type of generic element and size.
+///
+/// Stores one component per grade 4 basis blade, C(DIM, 4) in total, ordered lexicographically.
+/// Identically zero below 4D, in 4D it is the pseudoscalar xyzw.
+///
+pub type QuadVector<T, const DIM: usize> = KVector<T, DIM, 4>;
+
+pub type QuadVector4<T> = QuadVector<T, 4>;
+pub type QuadVector5<T> = QuadVector<T, 5>;
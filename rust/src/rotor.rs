@@ -2,9 +2,10 @@
 
 use std::{
     fmt::Debug,
-    ops::{ Neg, Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign }
+    marker::PhantomData,
+    ops::{ Neg, Div, DivAssign, Mul, MulAssign }
 };
-use num::traits::{ Num, Float };
+use num::traits::{ Num, Float, NumCast, ConstZero };
 
 use linear_algebra::{
     ops::{
@@ -12,47 +13,90 @@ use linear_algebra::{
         Normalize,
         Conjugate
     },
+    matrix::Matrix,
     vector::Vector
 };
 
 use crate::{
     ops::{
-        //InteriorProduct,
-        //ExteriorProduct
+        GeometricInverse,
+        ExteriorProduct,
+        HodgeStar,
+        LeftContraction,
+        RightContraction,
+        HestenesInnerProduct,
+        FatDot,
+        ScalarProduct,
+        Reverse,
+        GradeInvolution,
+        CliffordConjugate,
+        Rotate,
         GeometricProduct
     },
     traits::{
         ScalarComponent,
         ScalarComponentMut,
         BiVectorComponent,
-        BiVectorComponentMut
+        BiVectorComponentMut,
+        QuadVectorComponent,
+        QuadVectorComponentMut
     },
-    bivector::BiVector
+    blade::binomial,
+    error::Error,
+    matrix,
+    metric::{
+        Metric,
+        Euclidean
+    },
+    kvector::KVector,
+    bivector::BiVector,
+    quadvector::QuadVector,
+    multivector::Multivector
 };
 
 /// A rotor type of generic element and size.
 ///
+/// Stores the even grades a rotor has up to 5D: a scalar, a [`BiVector`] and a [`QuadVector`]. The quadvector
+/// is empty below 4D and only nonzero for rotors turning in two planes at once.
+///
+/// The metric M decides what the basis vectors square to, it defaults to [`Euclidean`]. It only enters the
+/// products, the methods of the Euclidean `Rotor<T, DIM>` such as the constructors and [`Rotor::log`] have no
+/// counterpart in other metrics.
+///
 #[derive( Clone, Copy, Debug, Default )]
-pub struct Rotor<T, const DIM: usize>( T, BiVector<T, DIM> )
+pub struct Rotor<T, const DIM: usize, M = Euclidean>( T, BiVector<T, DIM>, QuadVector<T, DIM>, PhantomData<M> )
 where
     T: 'static + Default + Copy + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:;
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:;
 
-impl<T, const DIM: usize> Rotor<T, DIM>
+impl<T, const DIM: usize, M> Rotor<T, DIM, M>
 where
     T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
-    /// Creates a new const [`Rotor`].
+    /// Creates a new const [`Rotor`] with a zero quadvector part.
     ///
-    pub const fn new_const( scalar: T, bivector: BiVector<T, DIM> ) -> Self {
-        Self ( scalar, bivector )
+    pub const fn new_const( scalar: T, bivector: BiVector<T, DIM> ) -> Self
+    where
+        T: ConstZero
+    {
+        Self ( scalar, bivector, QuadVector::new_const( [ T::ZERO; binomial( DIM, 4 ) ] ), PhantomData )
     }
 
-    /// Creates a new [`Rotor`].
+    /// Creates a new [`Rotor`] with a zero quadvector part.
     ///
     pub fn new( scalar: T, bivector: BiVector<T, DIM> ) -> Self {
-        Self ( scalar, bivector )
+        Self ( scalar, bivector, QuadVector::default(), PhantomData )
+    }
+
+    /// Creates a new [`Rotor`] from all of its parts.
+    ///
+    pub const fn new_even( scalar: T, bivector: BiVector<T, DIM>, quadvector: QuadVector<T, DIM> ) -> Self {
+        Self ( scalar, bivector, quadvector, PhantomData )
     }
 
     /// Creates a new zero filled [`Rotor`].
@@ -61,116 +105,578 @@ where
     where
         T: Num
     {
-        Self ( T::zero(), BiVector::zero() )
+        Self ( T::zero(), BiVector::zero(), QuadVector::zero(), PhantomData )
+    }
+
+    /// Returns the inverse of the [`Rotor`], R⁻¹ = R̃ / ( R R̃ ), the reverse for a unit rotor.
+    ///
+    pub fn inverse( self ) -> Self
+    where
+        T: Num + Neg<Output = T>,
+        [(); 1 << DIM]:
+    {
+        self.geometric_inverse()
+    }
+}
+
+impl<T, const DIM: usize> Rotor<T, DIM>
+where
+    T: 'static + Copy + Default + Debug + Num + Float,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    /// Creates the [`Rotor`] turning by `angle` radians in the plane `plane`.
+    ///
+    /// R = cos( θ / 2 ) - sin( θ / 2 ) B̂, so the unit plane xy turns x towards y. A zero plane gives the identity.
+    /// The plane must be simple, see [`BiVector::is_simple`], this is checked in debug builds. A non-simple bivector
+    /// is no plane and gives no rotor here, use [`BiVector::exp`] for it.
+    ///
+    pub fn from_plane_angle( plane: BiVector<T, DIM>, angle: T ) -> Self
+    where
+        [(); binomial( DIM, 4 )]:,
+        [(); binomial( DIM, 2 + 2 )]:
+    {
+        let magnitude = plane.magnitude();
+        if magnitude == T::zero() {
+            return Self::new( T::one(), BiVector::zero() );
+        }
+        debug_assert!( plane.is_simple( T::epsilon().sqrt() * magnitude * magnitude ), "the plane must be a simple bivector" );
+        let half = angle / ( T::one() + T::one() );
+        Self::new( half.cos(), plane * ( -half.sin() / magnitude ) )
+    }
+
+    /// Creates the [`Rotor`] turning the direction of `from` onto the direction of `to` in the plane they span.
+    ///
+    /// R = h â with ĥ the unit vector halfway between â and b̂. Antiparallel inputs span no plane, so they
+    /// turn by π in the plane of `from` and the basis vector least aligned with it. A zero input gives the identity.
+    ///
+    pub fn from_to( from: Vector<T, DIM>, to: Vector<T, DIM> ) -> Self {
+        let ( from_magnitude, to_magnitude ) = ( from.magnitude(), to.magnitude() );
+        if from_magnitude == T::zero() || to_magnitude == T::zero() {
+            return Self::new( T::one(), BiVector::zero() );
+        }
+        let a = from * ( T::one() / from_magnitude );
+        let b = to * ( T::one() / to_magnitude );
+        let mut half = Vector::<T, DIM>::default();
+        for i in 0..DIM {
+            half[ i ] = a[ i ] + b[ i ];
+        }
+        let magnitude = half.magnitude();
+        if magnitude <= T::epsilon().sqrt() {
+            let k = ( 0..DIM ).fold( 0, |k, i| if a[ i ].abs() < a[ k ].abs() { i } else { k } );
+            for i in 0..DIM {
+                half[ i ] = -a[ k ] * a[ i ];
+            }
+            half[ k ] = half[ k ] + T::one();
+        }
+        let half = half * ( T::one() / half.magnitude() );
+        Self::new( half.scalar_product( a ), half.exterior_product( a ) )
+    }
+
+    /// Returns the unit [`Rotor`] of the rotation closest to the matrix `src`, which rotates column vectors.
+    ///
+    /// A noisy matrix is first replaced by the orthogonal factor of its polar decomposition, the rotation matrix
+    /// closest to it in the Frobenius norm. The rotor then turns each basis vector onto its column in turn, each step in
+    /// a plane orthogonal to the columns already in place, which stays exact at half turns where the sum e_i f_i
+    /// vanishes. Fails with [`Error::Reflection`] if the determinant is negative and [`Error::Singular`] if it is zero.
+    ///
+    pub fn from_matrix( src: Matrix<T, DIM, DIM> ) -> Result<Self, Error>
+    where
+        [(); 1 << DIM]:
+    {
+        let mut m = [ [ T::zero(); DIM ]; DIM ];
+        for ( row, values ) in m.iter_mut().enumerate() {
+            for ( col, value ) in values.iter_mut().enumerate() {
+                *value = src[ ( row, col ) ];
+            }
+        }
+        let determinant = matrix::determinant( m );
+        if determinant < T::zero() {
+            return Err( Error::Reflection );
+        }
+        if determinant == T::zero() {
+            return Err( Error::Singular );
+        }
+        let m = matrix::polar( m ).ok_or( Error::Singular )?;
+        let axis = |i: usize| {
+            let mut res = Vector::<T, DIM>::default();
+            res[ i ] = T::one();
+            res
+        };
+        let half = T::one() / ( T::one() + T::one() );
+        let mut res = Self::new( T::one(), BiVector::zero() );
+        for i in 0..DIM {
+            let mut column = Vector::<T, DIM>::default();
+            for ( row, values ) in m.iter().enumerate() {
+                column[ row ] = values[ i ];
+            }
+            let from = res.rotate( axis( i ) );
+            res = if i + 1 < DIM && from.scalar_product( column ) < -half {
+                // Nearly a half turn, go round through the image of the next axis, which is orthogonal to both the
+                // current image and the columns already in place
+                let through = res.rotate( axis( i + 1 ) );
+                Self::from_to( through, column ) * Self::from_to( from, through ) * res
+            } else {
+                Self::from_to( from, column ) * res
+            };
+        }
+        Ok( res )
+    }
+
+    /// Returns the unit [`Rotor`] of the rotation closest to the linear map taking each basis vector e_i to `images[ i ]`.
+    ///
+    /// See [`Rotor::from_matrix`], the images are the columns of the matrix.
+    ///
+    pub fn from_basis( images: [ Vector<T, DIM>; DIM ] ) -> Result<Self, Error>
+    where
+        [(); 1 << DIM]:
+    {
+        let mut matrix = Matrix::<T, DIM, DIM>::default();
+        for ( col, image ) in images.iter().enumerate() {
+            for row in 0..DIM {
+                matrix[ ( row, col ) ] = image[ row ];
+            }
+        }
+        Self::from_matrix( matrix )
+    }
+
+    /// Returns the logarithm of the [`Rotor`], the [`BiVector`] B with e^B = R, see [`BiVector::exp`].
+    ///
+    /// A unit rotor is R = e^( θ₁B̂₁ ) e^( θ₂B̂₂ ), so its bivector part splits into a B̂₁ + b B̂₂ with a = sin θ₁ cos θ₂ and
+    /// b = cos θ₁ sin θ₂, while its scalar is cos θ₁ cos θ₂ and its quadvector sin θ₁ sin θ₂ B̂₁B̂₂. θ₁ ± θ₂ then
+    /// follow from two atan2. Half turns have no unique logarithm: R = -1 turns in the first basis plane and
+    /// R = ±B̂₁B̂₂ in a pair of planes of its quadvector.
+    ///
+    pub fn log( &self ) -> BiVector<T, DIM>
+    where
+        [(); 1 << DIM]:
+    {
+        let ( first, second ) = self.1.split();
+        let ( a, b ) = ( first.magnitude(), second.magnitude() );
+        let quadvector = self.2.magnitude();
+        let ( first, second ) = if b > T::zero() {
+            ( first / a, second / b )
+        } else if quadvector > T::zero() {
+            // B̂₂ = -J B̂₁ with J = B̂₁B̂₂ the unit quadvector
+            let j = Multivector::<T, DIM>::from( self.2 ) / quadvector;
+            let first = if a > T::zero() {
+                first / a
+            } else {
+                // R = ±J, project the basis plane of the largest quadvector blade onto J
+                let ( mask, _ ) = j.iter().enumerate()
+                    .fold( ( 0, T::zero() ), |best, ( mask, &x )| if x.abs() > best.1 { ( mask, x.abs() ) } else { best } );
+                let low = mask & mask.wrapping_neg();
+                let high = ( mask ^ low ) & ( mask ^ low ).wrapping_neg();
+                let mut plane = Multivector::<T, DIM>::zero();
+                plane[ low | high ] = T::one();
+                let plane = plane.left_contraction( j ).geometric_product( j ).bivector();
+                plane / plane.magnitude()
+            };
+            ( first, -j.geometric_product( Multivector::from( first ) ).bivector() )
+        } else if a > T::zero() {
+            return first * ( a.atan2( self.0 ) / a );
+        } else if self.0 < T::zero() {
+            let mut res = BiVector::zero();
+            res[ 0 ] = a.atan2( self.0 );
+            return res;
+        } else {
+            return BiVector::zero();
+        };
+        let q = self.2.iter().zip( Multivector::<T, DIM>::from( first ).exterior_product( Multivector::from( second ) ).kvector::<4>().iter() )
+            .fold( T::zero(), |acc, ( &x, &y )| acc + x * y );
+        let sum = ( a + b ).atan2( self.0 - q );
+        let difference = ( a - b ).atan2( self.0 + q );
+        let two = T::one() + T::one();
+        first * ( ( sum + difference ) / two ) + second * ( ( sum - difference ) / two )
+    }
+
+    /// Returns whichever of `other` and -`other` is closer to `self`, both stand for the same rotation.
+    ///
+    fn nearest( self, other: Self ) -> Self
+    where
+        [(); 1 << DIM]:
+    {
+        if self.scalar_product( other.reverse() ) < T::zero() {
+            Self::new_even( -other.0, -other.1, -other.2 )
+        } else {
+            other
+        }
+    }
+
+    /// Spherically interpolates from `self` at `t` = 0 to `other` at `t` = 1 at constant angular speed.
+    ///
+    /// R( t ) = R₀ e^( t log( R̃₀ R₁ ) ) with R₁ whichever of ±`other` is closer, so it takes the shortest way round.
+    /// Stays stable for nearly equal rotors, as neither exp nor log divides by the angle. Expects unit rotors.
+    ///
+    pub fn slerp( self, other: Self, t: T ) -> Self
+    where
+        [(); 1 << DIM]:
+    {
+        let delta = self.reverse() * self.nearest( other );
+        self * ( delta.log() * t ).exp()
+    }
+
+    /// Linearly interpolates from `self` at `t` = 0 to `other` at `t` = 1 and normalizes the result.
+    ///
+    /// Cheaper than [`Rotor::slerp`] and takes the same path, but not at constant angular speed.
+    ///
+    pub fn nlerp( self, other: Self, t: T ) -> Self
+    where
+        [(); 1 << DIM]:
+    {
+        let res = Multivector::<T, DIM>::from( self ) * ( T::one() - t ) + Multivector::from( self.nearest( other ) ) * t;
+        ( res / res.scalar_product( res.reverse() ).sqrt() ).rotor()
+    }
+
+    /// Spherical cubic interpolation from `self` at `t` = 0 to `other` at `t` = 1 with the inner control rotors `a` and `b`.
+    ///
+    /// squad( R₀, A, B, R₁, t ) = slerp( slerp( R₀, R₁, t ), slerp( A, B, t ), 2t( 1 - t ) ).
+    ///
+    pub fn squad( self, a: Self, b: Self, other: Self, t: T ) -> Self
+    where
+        [(); 1 << DIM]:
+    {
+        let two = T::one() + T::one();
+        self.slerp( other, t ).slerp( a.slerp( b, t ), two * t * ( T::one() - t ) )
+    }
+
+    /// Returns the inner squad control rotor at the key `current` between the keys `previous` and `next`.
+    ///
+    /// A = R e^( -( log( R̃ R₊ ) + log( R̃ R₋ ) ) / 4 ), which makes consecutive squad segments join with a continuous
+    /// angular velocity.
+    ///
+    pub fn squad_control( previous: Self, current: Self, next: Self ) -> Self
+    where
+        [(); 1 << DIM]:
+    {
+        let four = T::one() + T::one() + T::one() + T::one();
+        let to_next = ( current.reverse() * current.nearest( next ) ).log();
+        let to_previous = ( current.reverse() * current.nearest( previous ) ).log();
+        current * ( ( to_next + to_previous ) * ( -T::one() / four ) ).exp()
+    }
+
+    /// Interpolates the key rotors `keys` with a squad spline, `t` running from 0 at the first key to
+    /// `keys.len() - 1` at the last.
+    ///
+    /// The keys around the current segment are taken on the same hemisphere as its first key, so the spline
+    /// takes the shortest way between keys. Panics if `keys` is empty.
+    ///
+    pub fn squad_spline( keys: &[Self], t: T ) -> Self
+    where
+        [(); 1 << DIM]:
+    {
+        let last = keys.len() - 1;
+        if last == 0 {
+            return keys[ 0 ];
+        }
+        let t = t.max( T::zero() ).min( <T as NumCast>::from( last ).unwrap() );
+        let i = t.floor().to_usize().unwrap().min( last - 1 );
+        let start = keys[ i ];
+        let end = start.nearest( keys[ i + 1 ] );
+        let previous = start.nearest( keys[ i.saturating_sub( 1 ) ] );
+        let next = end.nearest( keys[ ( i + 2 ).min( last ) ] );
+        start.squad(
+            Self::squad_control( previous, start, end ),
+            Self::squad_control( start, end, next ),
+            end,
+            t - <T as NumCast>::from( i ).unwrap()
+        )
+    }
+
+    /// Returns the weighted Karcher mean of the unit rotors in `rotors`, the rotor minimizing the weighted sum of
+    /// squared angles to them, with a tolerance of √ε and at most 32 iterations.
+    ///
+    /// See [`Rotor::mean_with`].
+    ///
+    pub fn mean<I>( rotors: I ) -> Self
+    where
+        I: IntoIterator<Item = ( T, Self )>,
+        [(); 1 << DIM]:
+    {
+        Self::mean_with( rotors, T::epsilon().sqrt(), 32 )
+    }
+
+    /// Returns the weighted Karcher mean of the unit rotors in `rotors`, given as ( weight, rotor ) pairs.
+    ///
+    /// Starts from [`Rotor::chordal_mean`] and repeatedly moves R to R e^δ with δ = Σ wᵢ log( R̃ Rᵢ ) / Σ wᵢ, each Rᵢ taken
+    /// on the hemisphere of the current R, until |δ| drops below `tolerance` or after `iterations` steps. The mean is
+    /// unique if the rotors lie within a quarter turn of each other. Gives the identity if the weights sum to zero.
+    ///
+    pub fn mean_with<I>( rotors: I, tolerance: T, iterations: usize ) -> Self
+    where
+        I: IntoIterator<Item = ( T, Self )>,
+        [(); 1 << DIM]:
+    {
+        let rotors = rotors.into_iter().collect::<Vec<_>>();
+        let total = rotors.iter().fold( T::zero(), |acc, &( weight, _ )| acc + weight );
+        if total == T::zero() {
+            return Self::new( T::one(), BiVector::zero() );
+        }
+        let mut res = Self::chordal_mean( rotors.iter().copied() );
+        for _ in 0..iterations {
+            let delta = rotors.iter()
+                .fold( BiVector::zero(), |acc, &( weight, rotor )| acc + ( res.reverse() * res.nearest( rotor ) ).log() * weight ) / total;
+            res *= delta.exp();
+            if delta.magnitude() < tolerance {
+                break;
+            }
+        }
+        res
+    }
+
+    /// Returns the normalized weighted sum of the unit rotors in `rotors`, given as ( weight, rotor ) pairs.
+    ///
+    /// Each rotor is first taken on the hemisphere of the one with the largest weight. A fast approximation of
+    /// [`Rotor::mean`], close to it for rotors near each other. Gives the identity if the sum vanishes. From 4D on the
+    /// sum need not be a rotor, it is taken back onto the rotors as the exponential of its logarithm.
+    ///
+    pub fn chordal_mean<I>( rotors: I ) -> Self
+    where
+        I: IntoIterator<Item = ( T, Self )>,
+        [(); 1 << DIM]:
+    {
+        let rotors = rotors.into_iter().collect::<Vec<_>>();
+        let reference = rotors.iter()
+            .fold( None, |best: Option<( T, Self )>, &( weight, rotor )| match best {
+                Some( ( best_weight, _ ) ) if best_weight.abs() >= weight.abs() => best,
+                _ => Some( ( weight, rotor ) )
+            } );
+        let Some( ( _, reference ) ) = reference else {
+            return Self::new( T::one(), BiVector::zero() );
+        };
+        let res = rotors.iter()
+            .fold( Multivector::<T, DIM>::zero(), |acc, &( weight, rotor )| acc + Multivector::from( reference.nearest( rotor ) ) * weight );
+        let norm_squared = res.scalar_product( res.reverse() );
+        if norm_squared <= T::zero() {
+            return Self::new( T::one(), BiVector::zero() );
+        }
+        let res = ( res / norm_squared.sqrt() ).rotor();
+        if DIM < 4 { res } else { res.log().exp() }
     }
 }
 
-impl<T, const DIM: usize> ScalarComponent<T> for Rotor<T, DIM>
+impl<T> Rotor3<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Creates the [`Rotor3`] turning by `angle` radians about `axis`, counterclockwise looking down the axis.
+    ///
+    /// The rotation plane is ⋆axis, so the z axis turns x towards y. A zero axis gives the identity.
+    ///
+    pub fn from_axis_angle( axis: Vector<T, 3>, angle: T ) -> Self {
+        Self::from_plane_angle( axis.hodge_star(), angle )
+    }
+}
+
+impl<T, const DIM: usize, M> ScalarComponent<T> for Rotor<T, DIM, M>
 where
     T: 'static + Copy + Default + std::fmt::Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
     fn scalar( &self ) -> &T {
         &self.0
     }
 }
 
-impl<T, const DIM: usize> ScalarComponentMut<T> for Rotor<T, DIM>
+impl<T, const DIM: usize, M> ScalarComponentMut<T> for Rotor<T, DIM, M>
 where
     T: 'static + Copy + Default + std::fmt::Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
     fn scalar_mut( &mut self ) -> &mut T {
         &mut self.0
     }
 }
 
-impl<T, const DIM: usize> BiVectorComponent<T, DIM> for Rotor<T, DIM>
+impl<T, const DIM: usize, M> BiVectorComponent<T, DIM> for Rotor<T, DIM, M>
 where
     T: 'static + Copy + Default + std::fmt::Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
     fn bivector( &self ) -> &BiVector<T, DIM> {
         &self.1
     }
 }
 
-impl<T, const DIM: usize> BiVectorComponentMut<T, DIM> for Rotor<T, DIM>
+impl<T, const DIM: usize, M> BiVectorComponentMut<T, DIM> for Rotor<T, DIM, M>
 where
     T: 'static + Copy + Default + std::fmt::Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
     fn bivector_mut( &mut self ) -> &mut BiVector<T, DIM> {
         &mut self.1
     }
 }
 
-impl<T, const DIM: usize> PartialEq for Rotor<T, DIM>
+impl<T, const DIM: usize, M> QuadVectorComponent<T, DIM> for Rotor<T, DIM, M>
+where
+    T: 'static + Copy + Default + std::fmt::Debug,
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    fn quadvector( &self ) -> &QuadVector<T, DIM> {
+        &self.2
+    }
+}
+
+impl<T, const DIM: usize, M> QuadVectorComponentMut<T, DIM> for Rotor<T, DIM, M>
+where
+    T: 'static + Copy + Default + std::fmt::Debug,
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    fn quadvector_mut( &mut self ) -> &mut QuadVector<T, DIM> {
+        &mut self.2
+    }
+}
+
+impl<T, const DIM: usize, M> PartialEq for Rotor<T, DIM, M>
 where
     T: 'static + Copy + Default + Debug + PartialEq,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
     fn eq( &self, other: &Self ) -> bool {
         self.0 == other.0 &&
-        self.1 == other.1
+        self.1 == other.1 &&
+        self.2 == other.2
     }
 }
 
-impl<T, const DIM: usize> From<( T, BiVector<T, DIM> )> for Rotor<T, DIM>
+impl<T, const DIM: usize, M> From<( T, BiVector<T, DIM> )> for Rotor<T, DIM, M>
 where
     T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
     fn from( src: ( T, BiVector<T, DIM> ) ) -> Self {
-        unsafe { *( &src as *const ( T, BiVector<T, DIM> ) as *const Rotor<T, DIM> ) } // SAFETY: This is safe because they are the same structure with a different name.
+        Self ( src.0, src.1, QuadVector::default(), PhantomData )
     }
 }
 
-impl<T, const DIM: usize> Into<( T, BiVector<T, DIM> )> for Rotor<T, DIM>
+impl<T, const DIM: usize, M> From<Rotor<T, DIM, M>> for ( T, BiVector<T, DIM> )
 where
     T: 'static + Copy + Default + Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
-    fn into( self ) -> ( T, BiVector<T, DIM> ) {
-        unsafe { *( &self as *const Rotor<T, DIM> as *const ( T, BiVector<T, DIM> ) ) } // SAFETY: This is safe because they are the same structure with a different name.
+    fn from( src: Rotor<T, DIM, M> ) -> Self {
+        ( src.0, src.1 )
     }
 }
 
-impl<T, const DIM: usize> Magnitude for Rotor<T, DIM>
+/// |R| = sqrt( |R R̃| ), the scalar part of R R̃ taken in the metric M
+///
+impl<T, const DIM: usize, M> Magnitude for Rotor<T, DIM, M>
 where
     T: Default + Copy + Debug + Div<Output = T> + Num + Float,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
     type Output = T;
 
     fn magnitude( &self ) -> Self::Output {
-        self.0.sqrt() + self.1.magnitude()
+        self.scalar_product( self.reverse() ).abs().sqrt()
+    }
+}
+
+/// R⁻¹ = R̃ / ( R R̃ )
+///
+impl<T, const DIM: usize, M> GeometricInverse for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Rotor<T, DIM, M>;
+
+    fn geometric_inverse( self ) -> Self::Output {
+        let norm_squared = self.scalar_product( self.reverse() );
+        let reverse = self.reverse();
+        Rotor::new_even( reverse.0 / norm_squared, reverse.1 / norm_squared, reverse.2 / norm_squared )
+    }
+}
+
+/// Rotor<T, DIM> * Rotor<T, DIM>
+///
+/// Composes the rotations, `self * rhs` applies `rhs` first. Keeps the scalar, bivector and quadvector parts,
+/// which is all of the product up to 5D.
+///
+impl<T, const DIM: usize, M> Mul<Rotor<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Rotor<T, DIM, M>;
+
+    fn mul( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).geometric_product( rhs ).rotor()
     }
 }
 
-impl<T, const DIM: usize> Normalize for Rotor<T, DIM>
+impl<T, const DIM: usize, M> MulAssign<Rotor<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    fn mul_assign( &mut self, rhs: Rotor<T, DIM, M> ) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T, const DIM: usize, M> Normalize for Rotor<T, DIM, M>
 where
     T: Default + Copy + Debug + Div<Output = T> + DivAssign<T> + Num + Float,
+    M: Metric,
     BiVector<T, DIM>: DivAssign<T>,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    QuadVector<T, DIM>: DivAssign<T>,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
-    type Output = Rotor<T, DIM>;
+    type Output = Rotor<T, DIM, M>;
 
     fn normalize( mut self ) -> Self::Output {
         let magnitude = self.magnitude();
         self.0 /= magnitude;
         self.1 /= magnitude;
+        self.2 /= magnitude;
         self
     }
 }
 
-impl<T, const DIM: usize> Conjugate for Rotor<T, DIM>
+impl<T, const DIM: usize, M> Conjugate for Rotor<T, DIM, M>
 where
     T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
     BiVector<T, DIM>: Neg<Output = BiVector<T, DIM>>,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
-    type Output = Rotor<T, DIM>;
+    type Output = Rotor<T, DIM, M>;
 
     fn conjugate( mut self ) -> Self::Output {
         self.1 = -self.1;
@@ -178,23 +684,992 @@ where
     }
 }
 
-/*
-impl<T, const DIM: usize> Mul<Vector<T, DIM>> for Rotor<T, DIM>
+impl<T, const DIM: usize, M> Reverse for Rotor<T, DIM, M>
 where
-    T: Default + Copy + Debug + Div<Output = T> + DivAssign<T> + Num + Float,
-    BiVector<T, DIM>: DivAssign<T>,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Rotor<T, DIM, M>;
+
+    fn reverse( mut self ) -> Self::Output {
+        self.1 = -self.1;
+        self
+    }
+}
+
+impl<T, const DIM: usize, M> GradeInvolution for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug,
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Rotor<T, DIM, M>;
+
+    fn grade_involution( self ) -> Self::Output {
+        self
+    }
+}
+
+impl<T, const DIM: usize, M> CliffordConjugate for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Neg<Output = T>,
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Rotor<T, DIM, M>;
+
+    fn clifford_conjugate( mut self ) -> Self::Output {
+        self.1 = -self.1;
+        self
+    }
+}
+
+/// Rotor<T, DIM> ⌋ Rotor<T, DIM>
+///
+impl<T, const DIM: usize, M> LeftContraction<Rotor<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn left_contraction( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).left_contraction( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> ⌊ Rotor<T, DIM>
+///
+impl<T, const DIM: usize, M> RightContraction<Rotor<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn right_contraction( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).right_contraction( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> · Rotor<T, DIM>
+///
+impl<T, const DIM: usize, M> HestenesInnerProduct<Rotor<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn hestenes_inner_product( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).hestenes_inner_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> • Rotor<T, DIM>
+///
+impl<T, const DIM: usize, M> FatDot<Rotor<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn fat_dot( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).fat_dot( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> * Rotor<T, DIM>
+///
+impl<T, const DIM: usize, M> ScalarProduct<Rotor<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = T;
+
+    fn scalar_product( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).scalar_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> ⌋ Vector<T, DIM>
+///
+impl<T, const DIM: usize, M> LeftContraction<Vector<T, DIM>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn left_contraction( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).left_contraction( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> ⌊ Vector<T, DIM>
+///
+impl<T, const DIM: usize, M> RightContraction<Vector<T, DIM>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn right_contraction( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).right_contraction( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> · Vector<T, DIM>
+///
+impl<T, const DIM: usize, M> HestenesInnerProduct<Vector<T, DIM>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn hestenes_inner_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).hestenes_inner_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> • Vector<T, DIM>
+///
+impl<T, const DIM: usize, M> FatDot<Vector<T, DIM>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn fat_dot( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).fat_dot( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> * Vector<T, DIM>
+///
+impl<T, const DIM: usize, M> ScalarProduct<Vector<T, DIM>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = T;
+
+    fn scalar_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).scalar_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Vector<T, DIM> ⌋ Rotor<T, DIM>
+///
+impl<T, const DIM: usize, M> LeftContraction<Rotor<T, DIM, M>> for Vector<T, DIM>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn left_contraction( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).left_contraction( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Vector<T, DIM> ⌊ Rotor<T, DIM>
+///
+impl<T, const DIM: usize, M> RightContraction<Rotor<T, DIM, M>> for Vector<T, DIM>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn right_contraction( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).right_contraction( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Vector<T, DIM> · Rotor<T, DIM>
+///
+impl<T, const DIM: usize, M> HestenesInnerProduct<Rotor<T, DIM, M>> for Vector<T, DIM>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn hestenes_inner_product( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).hestenes_inner_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Vector<T, DIM> • Rotor<T, DIM>
+///
+impl<T, const DIM: usize, M> FatDot<Rotor<T, DIM, M>> for Vector<T, DIM>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn fat_dot( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).fat_dot( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Vector<T, DIM> * Rotor<T, DIM>
+///
+impl<T, const DIM: usize, M> ScalarProduct<Rotor<T, DIM, M>> for Vector<T, DIM>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = T;
+
+    fn scalar_product( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).scalar_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> ⌋ KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> LeftContraction<KVector<T, DIM, GRADE>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn left_contraction( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).left_contraction( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> ⌊ KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> RightContraction<KVector<T, DIM, GRADE>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn right_contraction( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).right_contraction( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> · KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> HestenesInnerProduct<KVector<T, DIM, GRADE>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn hestenes_inner_product( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).hestenes_inner_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> • KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> FatDot<KVector<T, DIM, GRADE>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn fat_dot( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).fat_dot( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Rotor<T, DIM> * KVector<T, DIM, GRADE>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> ScalarProduct<KVector<T, DIM, GRADE>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = T;
+
+    fn scalar_product( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).scalar_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// KVector<T, DIM, GRADE> ⌋ Rotor<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> LeftContraction<Rotor<T, DIM, M>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn left_contraction( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).left_contraction( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// KVector<T, DIM, GRADE> ⌊ Rotor<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> RightContraction<Rotor<T, DIM, M>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn right_contraction( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).right_contraction( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// KVector<T, DIM, GRADE> · Rotor<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> HestenesInnerProduct<Rotor<T, DIM, M>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn hestenes_inner_product( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).hestenes_inner_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// KVector<T, DIM, GRADE> • Rotor<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> FatDot<Rotor<T, DIM, M>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = Multivector<T, DIM, M>;
+
+    fn fat_dot( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).fat_dot( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// KVector<T, DIM, GRADE> * Rotor<T, DIM>
+///
+impl<T, const DIM: usize, const GRADE: usize, M> ScalarProduct<Rotor<T, DIM, M>> for KVector<T, DIM, GRADE>
+where
+    T: Default + Copy + Debug + Num,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = T;
+
+    fn scalar_product( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        Multivector::<T, DIM, M>::from( self ).scalar_product( Multivector::<T, DIM, M>::from( rhs ) )
+    }
+}
+
+/// Returns R v R̃ for the 3D Euclidean rotor R = `scalar` + `bivector` without building a multivector.
+///
+/// R v = u + t I with u = s v + B ⌊ v and t I = B ∧ v, so R v R̃ = s u + B ⌊ u + t ⋆B.
+///
+fn rotate3<T>( scalar: T, bivector: [T; 3], v: [T; 3] ) -> [T; 3]
+where
+    T: Copy + Num + Neg<Output = T>
+{
+    let [ xy, xz, yz ] = bivector;
+    let contract = |u: [T; 3]| [
+        xy * u[ 1 ] + xz * u[ 2 ],
+        yz * u[ 2 ] - xy * u[ 0 ],
+        -( xz * u[ 0 ] + yz * u[ 1 ] )
+    ];
+    let bv = contract( v );
+    let u = [ scalar * v[ 0 ] + bv[ 0 ], scalar * v[ 1 ] + bv[ 1 ], scalar * v[ 2 ] + bv[ 2 ] ];
+    let t = xy * v[ 2 ] - xz * v[ 1 ] + yz * v[ 0 ];
+    let bu = contract( u );
+    [
+        scalar * u[ 0 ] + bu[ 0 ] + t * yz,
+        scalar * u[ 1 ] + bu[ 1 ] - t * xz,
+        scalar * u[ 2 ] + bu[ 2 ] + t * xy
+    ]
+}
+
+/// R Vector<T, DIM> R̃
+///
+impl<T, const DIM: usize, M> Rotate<Vector<T, DIM>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
 {
     type Output = Vector<T, DIM>;
 
-    fn mul( self, rhs: Vector<T, DIM> ) -> Vector<T, DIM> {
-        let multivector: ( T, BiVector<T, DIM> ) = self.into();
-        let multivector_conjugate: ( T, BiVector<T, DIM> ) = self.conjugate().into();
-        multivector * rhs * multivector_conjugate
+    fn rotate( self, rhs: Vector<T, DIM> ) -> Self::Output {
+        if DIM == 3 && M::is_euclidean( DIM ) {
+            let v = rotate3( self.0, [ self.1[ 0 ], self.1[ 1 ], self.1[ 2 ] ], [ rhs[ 0 ], rhs[ 1 ], rhs[ 2 ] ] );
+            let mut res = Vector::<T, DIM>::default();
+            res.iter_mut().zip( v ).for_each( |( a, b )| *a = b );
+            return res;
+        }
+        if DIM == 2 && M::is_euclidean( DIM ) {
+            // xy anticommutes with vectors, so R v R̃ = R² v with R² = s² - b² + 2 s b xy
+            let ( s, b ) = ( self.0, self.1[ 0 ] );
+            let ( c, d ) = ( s * s - b * b, ( s + s ) * b );
+            let mut res = Vector::<T, DIM>::default();
+            res[ 0 ] = c * rhs[ 0 ] + d * rhs[ 1 ];
+            res[ 1 ] = c * rhs[ 1 ] - d * rhs[ 0 ];
+            return res;
+        }
+        Multivector::<T, DIM, M>::from( self )
+            .geometric_product( rhs )
+            .geometric_product( self.reverse() )
+            .vector()
+    }
+}
+
+/// R KVector<T, DIM, GRADE> R̃
+///
+impl<T, const DIM: usize, const GRADE: usize, M> Rotate<KVector<T, DIM, GRADE>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:,
+    [(); binomial( DIM, GRADE )]:
+{
+    type Output = KVector<T, DIM, GRADE>;
+
+    fn rotate( self, rhs: KVector<T, DIM, GRADE> ) -> Self::Output {
+        if DIM == 3 && GRADE == 2 && M::is_euclidean( DIM ) {
+            // A 3D bivector rotates like its Hodge dual axial vector, ⋆( xy, xz, yz ) = ( yz, -xz, xy )
+            let v = rotate3( self.0, [ self.1[ 0 ], self.1[ 1 ], self.1[ 2 ] ], [ rhs[ 2 ], -rhs[ 1 ], rhs[ 0 ] ] );
+            let mut res = KVector::<T, DIM, GRADE>::default();
+            res[ 0 ] = v[ 2 ];
+            res[ 1 ] = -v[ 1 ];
+            res[ 2 ] = v[ 0 ];
+            return res;
+        }
+        Multivector::<T, DIM, M>::from( self )
+            .geometric_product( rhs )
+            .geometric_product( self.reverse() )
+            .kvector::<GRADE>()
+    }
+}
+
+/// R Rotor<T, DIM> R̃
+///
+impl<T, const DIM: usize, M> Rotate<Rotor<T, DIM, M>> for Rotor<T, DIM, M>
+where
+    T: Default + Copy + Debug + Num + Neg<Output = T>,
+    M: Metric,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    type Output = Rotor<T, DIM, M>;
+
+    fn rotate( self, rhs: Rotor<T, DIM, M> ) -> Self::Output {
+        // R s R̃ = s R R̃, only the bivector and quadvector parts actually turn
+        Rotor::new_even( rhs.0 * self.scalar_product( self.reverse() ), self.rotate( rhs.1 ), self.rotate( rhs.2 ) )
     }
 }
-*/
 
 pub type Rotor2<T> = Rotor<T, 2>;
 pub type Rotor3<T> = Rotor<T, 3>;
 pub type Rotor4<T> = Rotor<T, 4>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{ EPSILON, assert_rotor };
+
+    #[test]
+    fn new_const() {
+        const IDENTITY: Rotor4<f64> = Rotor::new_const( 1.0, BiVector::new_const([ 0.0; 6 ]) );
+
+        assert_eq!( IDENTITY, Rotor4::new( 1.0, BiVector::zero() ) );
+    }
+
+    #[test]
+    fn rotate_vector_3() {
+        // A quarter turn in the xy plane, R = cos( θ / 2 ) - sin( θ / 2 ) xy
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        let rotor = Rotor3::<f64>::new( half, BiVector::new([ -half, 0.0, 0.0 ]) );
+
+        let res = rotor.rotate( Vector::<f64, 3>::new([ 1.0, 0.0, 0.0 ]) );
+
+        assert!( ( res[ 0 ] - 0.0 ).abs() < EPSILON );
+        assert!( ( res[ 1 ] - 1.0 ).abs() < EPSILON );
+        assert!( ( res[ 2 ] - 0.0 ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn rotate_vector_4() {
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        let rotor = Rotor4::<f64>::new( half, BiVector::new([ -half, 0.0, 0.0, 0.0, 0.0, 0.0 ]) );
+
+        let res = rotor.rotate( Vector::<f64, 4>::new([ 1.0, 0.0, 0.0, 2.0 ]) );
+
+        assert!( ( res[ 0 ] - 0.0 ).abs() < EPSILON );
+        assert!( ( res[ 1 ] - 1.0 ).abs() < EPSILON );
+        assert!( ( res[ 2 ] - 0.0 ).abs() < EPSILON );
+        assert!( ( res[ 3 ] - 2.0 ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn rotate_3_matches_sandwich() {
+        let rotor = Rotor3::<f64>::new( 0.3, BiVector::new([ 0.5, -0.7, 0.2 ]) );
+        let vector = Vector::<f64, 3>::new([ 1.3, -0.4, 2.1 ]);
+        let bivector = BiVector::<f64, 3>::new([ -0.6, 1.1, 0.8 ]);
+
+        let sandwich = |x: Multivector<f64, 3>| Multivector::from( rotor )
+            .geometric_product( x )
+            .geometric_product( rotor.reverse() );
+
+        let fast = rotor.rotate( vector );
+        let slow = sandwich( Multivector::from( vector ) ).vector();
+        assert!( fast.iter().zip( slow.iter() ).all( |( a, b )| ( a - b ).abs() < EPSILON ) );
+
+        let fast = rotor.rotate( bivector );
+        let slow = sandwich( Multivector::from( bivector ) ).bivector();
+        assert!( fast.iter().zip( slow.iter() ).all( |( a, b )| ( a - b ).abs() < EPSILON ) );
+    }
+
+    #[test]
+    fn from_plane_angle_3() {
+        let rotor = Rotor3::<f64>::from_plane_angle( BiVector::new([ 2.0, 0.0, 0.0 ]), std::f64::consts::FRAC_PI_2 );
+
+        let res = rotor.rotate( Vector::<f64, 3>::new([ 1.0, 0.0, 0.0 ]) );
+
+        assert!( ( res[ 0 ] - 0.0 ).abs() < EPSILON );
+        assert!( ( res[ 1 ] - 1.0 ).abs() < EPSILON );
+        assert!( ( res[ 2 ] - 0.0 ).abs() < EPSILON );
+    }
+
+    #[test]
+    #[cfg( debug_assertions )]
+    #[should_panic]
+    fn from_plane_angle_non_simple() {
+        Rotor4::<f64>::from_plane_angle( BiVector::new([ 1.0, 0.0, 0.0, 0.0, 0.0, 1.0 ]), 0.5 );
+    }
+
+    #[test]
+    fn from_axis_angle_3() {
+        let rotor = Rotor3::<f64>::from_axis_angle( Vector::new([ 0.0, 0.0, 1.0 ]), std::f64::consts::FRAC_PI_2 );
+
+        assert_eq!( rotor, Rotor3::<f64>::from_plane_angle( BiVector::new([ 1.0, 0.0, 0.0 ]), std::f64::consts::FRAC_PI_2 ) );
+    }
+
+    #[test]
+    fn from_to_3() {
+        let from = Vector::<f64, 3>::new([ 1.0, 2.0, -1.0 ]);
+        let to = Vector::<f64, 3>::new([ -3.0, 0.5, 2.0 ]);
+
+        let res = Rotor3::<f64>::from_to( from, to ).rotate( from );
+
+        let scale = from.magnitude() / to.magnitude();
+        assert!( res.iter().zip( to.iter() ).all( |( a, b )| ( a - b * scale ).abs() < EPSILON ) );
+    }
+
+    #[test]
+    fn from_to_antiparallel_4() {
+        let from = Vector::<f64, 4>::new([ 1.0, 2.0, -1.0, 0.5 ]);
+        let to = -from;
+
+        let rotor = Rotor4::<f64>::from_to( from, to );
+        let res = rotor.rotate( from );
+
+        assert!( ( rotor.scalar_product( rotor.reverse() ) - 1.0 ).abs() < EPSILON );
+        assert!( res.iter().zip( to.iter() ).all( |( a, b )| ( a - b ).abs() < EPSILON ) );
+    }
+
+    #[test]
+    fn exp_3() {
+        let plane = BiVector::<f64, 3>::new([ 0.6, 0.0, 0.8 ]);
+        let angle = 1.2;
+
+        let res = ( plane * ( -angle / 2.0 ) ).exp();
+
+        assert_rotor( res, Rotor3::<f64>::from_plane_angle( plane, angle ) );
+    }
+
+    #[test]
+    fn exp_non_simple_4() {
+        // xy + zw turns by 0.3 in xy and 0.7 in zw at once
+        let bivector = BiVector::<f64, 4>::new([ 0.3, 0.0, 0.0, 0.0, 0.0, 0.7 ]);
+
+        let res = bivector.exp();
+
+        let ( c0, s0, c1, s1 ) = ( 0.3f64.cos(), 0.3f64.sin(), 0.7f64.cos(), 0.7f64.sin() );
+        assert_rotor( res, Rotor4::new_even( c0 * c1, BiVector::new([ s0 * c1, 0.0, 0.0, 0.0, 0.0, c0 * s1 ]), QuadVector::new([ s0 * s1 ]) ) );
+    }
+
+    #[test]
+    fn log_exp_4() {
+        for bivector in [
+            BiVector::<f64, 4>::new([ 0.1, 0.2, 0.3, 0.4, 0.5, 0.6 ]),
+            BiVector::<f64, 4>::new([ 1.2, -0.3, 0.4, 0.9, 0.1, -1.1 ]),
+            BiVector::<f64, 4>::new([ 0.5, 0.0, 0.0, 0.0, 0.0, 0.5 ]), // isoclinic
+            BiVector::<f64, 4>::new([ 0.0, 0.0, 0.0, 0.0, 0.0, 0.4 ])
+        ] {
+            let res = bivector.exp().log();
+
+            assert!( res.iter().zip( bivector.iter() ).all( |( a, b )| ( a - b ).abs() < EPSILON ) );
+        }
+    }
+
+    #[test]
+    fn split_4() {
+        let bivector = BiVector::<f64, 4>::new([ 1.2, -0.3, 0.4, 0.9, 0.1, -1.1 ]);
+
+        let ( first, second ) = bivector.split();
+
+        assert!( first.is_simple( EPSILON ) );
+        assert!( second.is_simple( EPSILON ) );
+        assert!( first.scalar_product( second ).abs() < EPSILON );
+        assert!( ( first + second ).iter().zip( bivector.iter() ).all( |( a, b )| ( a - b ).abs() < EPSILON ) );
+    }
+
+    #[test]
+    fn slerp_3() {
+        let z = Vector::<f64, 3>::new([ 0.0, 0.0, 1.0 ]);
+        let identity = Rotor3::<f64>::new( 1.0, BiVector::zero() );
+        let quarter = Rotor3::<f64>::from_axis_angle( z, std::f64::consts::FRAC_PI_2 );
+
+        let res = identity.slerp( quarter, 0.5 );
+        let expected = Rotor3::<f64>::from_axis_angle( z, std::f64::consts::FRAC_PI_4 );
+
+        assert_rotor( res, expected );
+    }
+
+    #[test]
+    fn slerp_shortest_path_3() {
+        let from = Rotor3::<f64>::from_axis_angle( Vector::new([ 1.0, 2.0, 0.5 ]), 0.4 );
+        let to = Rotor3::<f64>::from_axis_angle( Vector::new([ -0.3, 1.0, 2.0 ]), 1.9 );
+        let flipped = Rotor3::<f64>::new_even( -to.0, -to.1, -to.2 );
+
+        let res = from.slerp( to, 0.3 );
+        let flipped = from.slerp( flipped, 0.3 );
+
+        assert_rotor( res, flipped );
+    }
+
+    #[test]
+    fn slerp_nearly_equal_3() {
+        let axis = Vector::<f64, 3>::new([ 1.0, 2.0, 0.5 ]);
+        let from = Rotor3::<f64>::from_axis_angle( axis, 0.4 );
+        let to = Rotor3::<f64>::from_axis_angle( axis, 0.4 + 1e-10 );
+
+        let res = from.slerp( to, 0.5 );
+
+        assert!( ( res.0 - from.0 ).abs() < 1e-9 );
+        assert!( res.1.iter().zip( from.1.iter() ).all( |( a, b )| ( a - b ).abs() < 1e-9 ) );
+    }
+
+    #[test]
+    fn slerp_non_simple_4() {
+        let bivector = BiVector::<f64, 4>::new([ 0.1, 0.2, 0.3, 0.4, 0.5, 0.6 ]);
+        let identity = Rotor4::<f64>::new( 1.0, BiVector::zero() );
+
+        let res = identity.slerp( bivector.exp(), 0.5 );
+        let expected = ( bivector * 0.5 ).exp();
+
+        assert_rotor( res, expected );
+    }
+
+    #[test]
+    fn nlerp_3() {
+        let z = Vector::<f64, 3>::new([ 0.0, 0.0, 1.0 ]);
+        let from = Rotor3::<f64>::from_axis_angle( z, 0.2 );
+        let to = Rotor3::<f64>::from_axis_angle( z, 1.0 );
+
+        // Symmetric about the midpoint, so nlerp and slerp agree there
+        let res = from.nlerp( to, 0.5 );
+        let expected = Rotor3::<f64>::from_axis_angle( z, 0.6 );
+
+        assert_rotor( res, expected );
+    }
+
+    #[test]
+    fn squad_spline_keys_3() {
+        let keys = [
+            Rotor3::<f64>::from_axis_angle( Vector::new([ 0.0, 0.0, 1.0 ]), 0.0 ),
+            Rotor3::<f64>::from_axis_angle( Vector::new([ 0.0, 1.0, 1.0 ]), 0.8 ),
+            Rotor3::<f64>::from_axis_angle( Vector::new([ 1.0, 0.0, 1.0 ]), 1.7 ),
+            Rotor3::<f64>::from_axis_angle( Vector::new([ 1.0, 1.0, 0.0 ]), 2.5 )
+        ];
+
+        for ( i, key ) in keys.iter().enumerate() {
+            let res = Rotor3::squad_spline( &keys, i as f64 );
+
+            assert_rotor( res, *key );
+        }
+    }
+
+    #[test]
+    fn mean_3() {
+        let z = Vector::<f64, 3>::new([ 0.0, 0.0, 1.0 ]);
+        let light = Rotor3::<f64>::from_axis_angle( z, 0.2 );
+        let heavy = Rotor3::<f64>::from_axis_angle( z, 1.0 );
+        let flipped = Rotor3::<f64>::new_even( -light.0, -light.1, -light.2 );
+
+        let res = Rotor3::mean([ ( 1.0, light ), ( 3.0, heavy ) ]);
+        let hemisphere = Rotor3::mean([ ( 1.0, flipped ), ( 3.0, heavy ) ]);
+        let expected = Rotor3::<f64>::from_axis_angle( z, 0.8 );
+
+        for res in [ res, hemisphere ] {
+            assert_rotor( res, expected );
+        }
+    }
+
+    #[test]
+    fn mean_slerp_3() {
+        let from = Rotor3::<f64>::from_axis_angle( Vector::new([ 1.0, 2.0, 0.5 ]), 0.4 );
+        let to = Rotor3::<f64>::from_axis_angle( Vector::new([ -0.3, 1.0, 2.0 ]), 1.9 );
+
+        // The mean of two rotors is the midpoint of the geodesic between them
+        let res = Rotor3::mean_with([ ( 1.0, from ), ( 1.0, to ) ], 1e-13, 100 );
+        let expected = from.slerp( to, 0.5 );
+
+        assert_rotor( res, expected );
+    }
+
+    #[test]
+    fn mean_4() {
+        let double = |a: f64, b: f64| BiVector::<f64, 4>::new([ a, 0.0, 0.0, 0.0, 0.0, b ]).exp();
+
+        let res = Rotor4::mean([ ( 2.0, double( 0.3, -0.2 ) ), ( 1.0, double( 0.6, 0.7 ) ), ( 1.0, double( -0.1, 0.3 ) ) ]);
+        let expected = double( 0.275, 0.15 );
+
+        assert_rotor( res, expected );
+    }
+
+    #[test]
+    fn chordal_mean_3() {
+        let z = Vector::<f64, 3>::new([ 0.0, 0.0, 1.0 ]);
+        let from = Rotor3::<f64>::from_axis_angle( z, 0.2 );
+        let to = Rotor3::<f64>::from_axis_angle( z, 1.0 );
+
+        let res = Rotor3::chordal_mean([ ( 1.0, from ), ( 1.0, to ) ]);
+        let expected = Rotor3::<f64>::from_axis_angle( z, 0.6 );
+
+        assert_rotor( res, expected );
+        assert_eq!( Rotor3::<f64>::chordal_mean( [] ), Rotor3::new( 1.0, BiVector::zero() ) );
+    }
+
+    #[test]
+    fn from_matrix_noisy_3() {
+        let rotor = Rotor3::<f64>::from_axis_angle( Vector::new([ 1.0, 2.0, 0.5 ]), 2.2 );
+        let rotation = Matrix::<f64, 3, 3>::from( rotor );
+        let stretch = [ [ 1.01, 0.002, -0.003 ], [ 0.002, 0.995, 0.001 ], [ -0.003, 0.001, 1.004 ] ];
+
+        // Q( I + S ) with S symmetric has Q as its closest rotation
+        let mut noisy = Matrix::<f64, 3, 3>::default();
+        for row in 0..3 {
+            for col in 0..3 {
+                noisy[ ( row, col ) ] = ( 0..3 ).fold( 0.0, |acc, k| acc + rotation[ ( row, k ) ] * stretch[ k ][ col ] );
+            }
+        }
+        let res = Rotor3::from_matrix( noisy ).unwrap();
+
+        for col in 0..3 {
+            let mut axis = Vector::<f64, 3>::default();
+            axis[ col ] = 1.0;
+            let image = res.rotate( axis );
+            for row in 0..3 {
+                assert!( ( image[ row ] - rotation[ ( row, col ) ] ).abs() < EPSILON );
+            }
+        }
+    }
+
+    #[test]
+    fn from_basis_half_turns_4() {
+        let axis = |i: usize, sign: f64| {
+            let mut res = Vector::<f64, 4>::default();
+            res[ i ] = sign;
+            res
+        };
+
+        for signs in [ [ -1.0, -1.0, 1.0, 1.0 ], [ -1.0, -1.0, -1.0, -1.0 ], [ 1.0, -1.0, 1.0, -1.0 ] ] {
+            let res = Rotor4::from_basis( std::array::from_fn( |i| axis( i, signs[ i ] ) ) ).unwrap();
+
+            for ( i, &sign ) in signs.iter().enumerate() {
+                let image = res.rotate( axis( i, 1.0 ) );
+                assert!( ( 0..4 ).all( |row| ( image[ row ] - if row == i { sign } else { 0.0 } ).abs() < EPSILON ) );
+            }
+        }
+    }
+
+    #[test]
+    fn from_basis_5() {
+        let rotor = Rotor::<f64, 5>::from_plane_angle( BiVector::new([ 1.0, 0.3, 0.0, 0.5, 0.0, -0.4, 0.0, -0.12, 0.0, 0.2 ]), 2.9 ) *
+            Rotor::<f64, 5>::from_plane_angle( BiVector::new([ -0.35, -0.7, 0.0, 0.0, 0.0, -0.3, 0.5, -0.6, 1.0, 0.0 ]), 1.4 );
+        let images: [ Vector<f64, 5>; 5 ] = std::array::from_fn( |i| {
+            let mut axis = Vector::<f64, 5>::default();
+            axis[ i ] = 1.0;
+            rotor.rotate( axis )
+        } );
+
+        let res = Rotor::<f64, 5>::from_basis( images ).unwrap();
+
+        for ( i, image ) in images.iter().enumerate() {
+            let mut axis = Vector::<f64, 5>::default();
+            axis[ i ] = 1.0;
+            let res = res.rotate( axis );
+            assert!( ( 0..5 ).all( |row| ( res[ row ] - image[ row ] ).abs() < 1e-10 ) );
+        }
+    }
+
+    #[test]
+    fn from_matrix_errors_3() {
+        let mut reflection = Matrix::<f64, 3, 3>::default();
+        reflection[ ( 0, 0 ) ] = -1.0;
+        reflection[ ( 1, 1 ) ] = 1.0;
+        reflection[ ( 2, 2 ) ] = 1.0;
+        let mut singular = Matrix::<f64, 3, 3>::default();
+        singular[ ( 1, 1 ) ] = 1.0;
+        singular[ ( 2, 2 ) ] = 1.0;
+
+        assert_eq!( Rotor3::from_matrix( reflection ), Err( Error::Reflection ) );
+        assert_eq!( Rotor3::from_matrix( singular ), Err( Error::Singular ) );
+    }
+
+    #[test]
+    fn mul_3() {
+        let z = Vector::<f64, 3>::new([ 0.0, 0.0, 1.0 ]);
+
+        let res = Rotor3::<f64>::from_axis_angle( z, 0.5 ) * Rotor3::<f64>::from_axis_angle( z, 0.7 );
+        let expected = Rotor3::<f64>::from_axis_angle( z, 1.2 );
+
+        assert_rotor( res, expected );
+    }
+
+    #[test]
+    fn mul_4() {
+        // Turning in xy, then in the orthogonal zw, keeps the grade 4 part
+        let xy = BiVector::<f64, 4>::new([ 0.3, 0.0, 0.0, 0.0, 0.0, 0.0 ]);
+        let zw = BiVector::<f64, 4>::new([ 0.0, 0.0, 0.0, 0.0, 0.0, 0.7 ]);
+
+        let res = xy.exp() * zw.exp();
+        let expected = ( xy + zw ).exp();
+
+        assert_rotor( res, expected );
+        assert!( res.2[ 0 ].abs() > 0.1 );
+    }
+
+    #[test]
+    fn inverse_4() {
+        let bivector = BiVector::<f64, 4>::new([ 1.2, -0.3, 0.4, 0.9, 0.1, -1.1 ]);
+        let rotor = bivector.exp();
+        let rotor = Rotor4::<f64>::new_even( rotor.0 * 2.0, rotor.1 * 2.0, rotor.2 * 2.0 );
+
+        let res = rotor * rotor.inverse();
+
+        assert_rotor( res, Rotor4::new( 1.0, BiVector::zero() ) );
+    }
+
+    #[test]
+    fn magnitude_normalize_3() {
+        let rotor = Rotor3::<f64>::new( 3.0, BiVector::new([ 0.0, 4.0, 0.0 ]) );
+
+        assert!( ( rotor.magnitude() - 5.0 ).abs() < EPSILON );
+        assert!( ( rotor.normalize().magnitude() - 1.0 ).abs() < EPSILON );
+    }
+}
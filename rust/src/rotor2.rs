@@ -0,0 +1,130 @@
+// Copyright 2024 Bewusstsein Labs
+
+use std::fmt::Debug;
+use num::{
+    traits::{ Num, Float },
+    Complex
+};
+
+use crate::{
+    traits::{
+        ScalarComponent,
+        BiVectorComponent
+    },
+    bivector::BiVector,
+    rotor::Rotor2
+};
+
+impl<T> Rotor2<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Creates the [`Rotor2`] turning counterclockwise by `angle` radians, R = cos( θ / 2 ) - sin( θ / 2 ) xy.
+    ///
+    pub fn from_angle( angle: T ) -> Self {
+        Self::from_plane_angle( BiVector::new([ T::one() ]), angle )
+    }
+
+    /// Returns the counterclockwise angle in radians the [`Rotor2`] turns by, in ( -π, π ].
+    ///
+    pub fn angle( &self ) -> T {
+        let ( s, b ) = ( *self.scalar(), self.bivector()[ 0 ] );
+        // The argument of R² = s² - b² + 2 s b xy, with xy turning clockwise
+        ( -( s + s ) * b ).atan2( s * s - b * b )
+    }
+
+    /// Returns the complex number z with z v = R v R̃ for every vector v = x + i y.
+    ///
+    /// z is the square of the [`Complex`] image of R, ( s² - b² ) - 2 s b i. R and -R give the same z, the sign of the
+    /// rotor is lost.
+    ///
+    pub fn rotation_complex( &self ) -> Complex<T> {
+        let ( s, b ) = ( *self.scalar(), self.bivector()[ 0 ] );
+        Complex::new( s * s - b * b, -( s + s ) * b )
+    }
+
+    /// Creates the [`Rotor2`] R with R v R̃ = z v for every vector v = x + i y, with a non-negative scalar part.
+    ///
+    /// R is the image of the principal square root of z, so a non-unit z gives a rotor scaling by |z| as well.
+    ///
+    pub fn from_rotation_complex( z: Complex<T> ) -> Self {
+        Self::from( z.sqrt() )
+    }
+}
+
+/// The complex number s - b i of the [`Rotor2`] R = s + b xy.
+///
+/// The even subalgebra of the plane is the complex numbers with i = -xy, this is that isomorphism, so products map to
+/// products and the conversion round-trips. R turns vectors by the square of it, see [`Rotor2::rotation_complex`].
+///
+impl<T> From<Rotor2<T>> for Complex<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: Rotor2<T> ) -> Self {
+        Complex::new( *src.scalar(), -src.bivector()[ 0 ] )
+    }
+}
+
+/// The [`Rotor2`] re - im xy of the complex number z = re + im i, the inverse of the conversion to [`Complex`].
+///
+impl<T> From<Complex<T>> for Rotor2<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: Complex<T> ) -> Self {
+        Rotor2::new( src.re, BiVector::new([ -src.im ]) )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linear_algebra::vector::Vector;
+    use crate::{
+        ops::Rotate,
+        test_util::EPSILON
+    };
+
+    #[test]
+    fn angle() {
+        for angle in [ 0.0, 0.3, -1.2, 2.9, std::f64::consts::PI ] {
+            assert!( ( Rotor2::<f64>::from_angle( angle ).angle() - angle ).abs() < EPSILON );
+        }
+    }
+
+    #[test]
+    fn rotate() {
+        let rotor = Rotor2::<f64>::from_angle( std::f64::consts::FRAC_PI_2 );
+
+        let res = rotor.rotate( Vector::<f64, 2>::new([ 1.0, 2.0 ]) );
+
+        assert!( ( res[ 0 ] + 2.0 ).abs() < EPSILON );
+        assert!( ( res[ 1 ] - 1.0 ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn complex() {
+        let rotor = Rotor2::<f64>::from_angle( 0.9 );
+        let z = Complex::new( 0.6, 0.8 );
+
+        assert_eq!( Rotor2::from( Complex::from( rotor ) ), rotor );
+        assert_eq!( Complex::from( Rotor2::from( z ) ), z );
+        assert!( ( Complex::from( rotor * rotor ) - Complex::from( rotor ) * Complex::from( rotor ) ).norm() < EPSILON );
+    }
+
+    #[test]
+    fn rotation_complex() {
+        let z = Complex::new( 0.6, 0.8 );
+        let v = Vector::<f64, 2>::new([ 1.5, -0.5 ]);
+
+        let rotor = Rotor2::from_rotation_complex( z );
+        let res = rotor.rotate( v );
+        let expected = z * Complex::new( v[ 0 ], v[ 1 ] );
+
+        assert!( ( res[ 0 ] - expected.re ).abs() < EPSILON );
+        assert!( ( res[ 1 ] - expected.im ).abs() < EPSILON );
+        assert!( ( rotor.rotation_complex() - z ).norm() < EPSILON );
+        assert!( ( rotor.angle() - z.arg() ).abs() < EPSILON );
+    }
+}
@@ -0,0 +1,344 @@
+// Copyright 2024 Bewusstsein Labs
+
+use std::{
+    fmt::Debug,
+    ops::{ Mul, Neg }
+};
+use num::traits::{ Num, Float };
+
+use linear_algebra::{
+    matrix::Matrix,
+    vector::Vector
+};
+
+use crate::{
+    traits::{
+        ScalarComponent,
+        BiVectorComponent
+    },
+    error::Error,
+    matrix,
+    bivector::BiVector,
+    rotor::Rotor3
+};
+
+/// A quaternion w + x i + y j + z k in the Hamilton convention, ij = k.
+///
+/// A unit quaternion q rotates v as q v q* with right-handed rotations: turning by θ about the unit axis n is
+/// q = cos( θ / 2 ) + sin( θ / 2 ) n. It maps onto [`Rotor3`] by i = -yz, j = xz, k = -xy, which keeps the
+/// product order, so q₁ q₂ corresponds to R₁ R₂, and keeps the sign, so q and -q stay apart.
+///
+#[derive( Clone, Copy, Debug, Default, PartialEq )]
+pub struct Quaternion<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T
+}
+
+impl<T> Quaternion<T>
+where
+    T: Copy + Num + Neg<Output = T>
+{
+    /// Returns the conjugate w - x i - y j - z k, the inverse of a unit quaternion.
+    ///
+    pub fn conjugate( &self ) -> Self {
+        Self { w: self.w, x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+/// Quaternion<T> * Quaternion<T>
+///
+impl<T> Mul for Quaternion<T>
+where
+    T: Copy + Num
+{
+    type Output = Self;
+
+    fn mul( self, rhs: Self ) -> Self {
+        Self {
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w
+        }
+    }
+}
+
+impl<T> From<Rotor3<T>> for Quaternion<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: Rotor3<T> ) -> Self {
+        let bivector = src.bivector();
+        Self {
+            w: *src.scalar(),
+            x: -bivector[ 2 ],
+            y: bivector[ 1 ],
+            z: -bivector[ 0 ]
+        }
+    }
+}
+
+impl<T> From<Quaternion<T>> for Rotor3<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: Quaternion<T> ) -> Self {
+        Rotor3::new( src.w, BiVector::new([ -src.z, src.y, -src.x ]) )
+    }
+}
+
+/// Tait-Bryan angles in radians, applied intrinsically as yaw about z, then pitch about the new y, then roll
+/// about the new x.
+///
+/// The rotation is R = R_z( yaw ) R_y( pitch ) R_x( roll ), all turning counterclockwise looking down their axis.
+/// Pitch is in [ -π / 2, π / 2 ], yaw and roll in [ -π, π ]. At pitch = ±π / 2 ( gimbal lock ) only yaw ∓ roll is
+/// defined, the conversion from a [`Rotor3`] then puts all of it into yaw.
+///
+#[derive( Clone, Copy, Debug, Default, PartialEq )]
+pub struct EulerAngles<T> {
+    pub yaw: T,
+    pub pitch: T,
+    pub roll: T
+}
+
+impl<T> From<EulerAngles<T>> for Rotor3<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: EulerAngles<T> ) -> Self {
+        let axis = |i: usize| {
+            let mut res = Vector::<T, 3>::default();
+            res[ i ] = T::one();
+            res
+        };
+        Rotor3::from_axis_angle( axis( 2 ), src.yaw ) *
+        Rotor3::from_axis_angle( axis( 1 ), src.pitch ) *
+        Rotor3::from_axis_angle( axis( 0 ), src.roll )
+    }
+}
+
+impl<T> From<Rotor3<T>> for EulerAngles<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: Rotor3<T> ) -> Self {
+        let m = rotation_matrix( src.into() );
+        let cos_pitch = ( m[ 0 ][ 0 ] * m[ 0 ][ 0 ] + m[ 1 ][ 0 ] * m[ 1 ][ 0 ] ).sqrt();
+        let pitch = ( -m[ 2 ][ 0 ] ).atan2( cos_pitch );
+        if cos_pitch > T::epsilon().sqrt() {
+            Self {
+                yaw: m[ 1 ][ 0 ].atan2( m[ 0 ][ 0 ] ),
+                pitch,
+                roll: m[ 2 ][ 1 ].atan2( m[ 2 ][ 2 ] )
+            }
+        } else {
+            Self {
+                yaw: ( -m[ 0 ][ 1 ] ).atan2( m[ 1 ][ 1 ] ),
+                pitch,
+                roll: T::zero()
+            }
+        }
+    }
+}
+
+/// Returns the rotation matrix of the unit quaternion `q`, indexed [ row ][ column ], rotating column vectors.
+///
+fn rotation_matrix<T>( q: Quaternion<T> ) -> [ [ T; 3 ]; 3 ]
+where
+    T: Copy + Num
+{
+    let two = T::one() + T::one();
+    let Quaternion { w, x, y, z } = q;
+    [
+        [ T::one() - two * ( y * y + z * z ), two * ( x * y - w * z ), two * ( x * z + w * y ) ],
+        [ two * ( x * y + w * z ), T::one() - two * ( x * x + z * z ), two * ( y * z - w * x ) ],
+        [ two * ( x * z - w * y ), two * ( y * z + w * x ), T::one() - two * ( x * x + y * y ) ]
+    ]
+}
+
+/// Returns the unit quaternion of the rotation matrix `m`, indexed [ row ][ column ].
+///
+/// Shepperd's method: it solves for the largest of |w|, |x|, |y|, |z| from the diagonal first and divides only
+/// by that one, which stays accurate near 180° rotations, where w and the antisymmetric part vanish.
+///
+fn rotation_quaternion<T>( m: [ [ T; 3 ]; 3 ] ) -> Quaternion<T>
+where
+    T: Copy + Num + Float
+{
+    let ( one, two ) = ( T::one(), T::one() + T::one() );
+    let trace = m[ 0 ][ 0 ] + m[ 1 ][ 1 ] + m[ 2 ][ 2 ];
+    let q = if trace >= m[ 0 ][ 0 ] && trace >= m[ 1 ][ 1 ] && trace >= m[ 2 ][ 2 ] {
+        let w = ( one + trace ).sqrt() / two;
+        let d = two * two * w;
+        Quaternion { w, x: ( m[ 2 ][ 1 ] - m[ 1 ][ 2 ] ) / d, y: ( m[ 0 ][ 2 ] - m[ 2 ][ 0 ] ) / d, z: ( m[ 1 ][ 0 ] - m[ 0 ][ 1 ] ) / d }
+    } else if m[ 0 ][ 0 ] >= m[ 1 ][ 1 ] && m[ 0 ][ 0 ] >= m[ 2 ][ 2 ] {
+        let x = ( one + m[ 0 ][ 0 ] - m[ 1 ][ 1 ] - m[ 2 ][ 2 ] ).sqrt() / two;
+        let d = two * two * x;
+        Quaternion { w: ( m[ 2 ][ 1 ] - m[ 1 ][ 2 ] ) / d, x, y: ( m[ 0 ][ 1 ] + m[ 1 ][ 0 ] ) / d, z: ( m[ 0 ][ 2 ] + m[ 2 ][ 0 ] ) / d }
+    } else if m[ 1 ][ 1 ] >= m[ 2 ][ 2 ] {
+        let y = ( one - m[ 0 ][ 0 ] + m[ 1 ][ 1 ] - m[ 2 ][ 2 ] ).sqrt() / two;
+        let d = two * two * y;
+        Quaternion { w: ( m[ 0 ][ 2 ] - m[ 2 ][ 0 ] ) / d, x: ( m[ 0 ][ 1 ] + m[ 1 ][ 0 ] ) / d, y, z: ( m[ 1 ][ 2 ] + m[ 2 ][ 1 ] ) / d }
+    } else {
+        let z = ( one - m[ 0 ][ 0 ] - m[ 1 ][ 1 ] + m[ 2 ][ 2 ] ).sqrt() / two;
+        let d = two * two * z;
+        Quaternion { w: ( m[ 1 ][ 0 ] - m[ 0 ][ 1 ] ) / d, x: ( m[ 0 ][ 2 ] + m[ 2 ][ 0 ] ) / d, y: ( m[ 1 ][ 2 ] + m[ 2 ][ 1 ] ) / d, z }
+    };
+    // Keep the scalar part non-negative and absorb the rounding of a nearly orthonormal matrix
+    let norm = ( q.w * q.w + q.x * q.x + q.y * q.y + q.z * q.z ).sqrt();
+    let norm = if q.w < T::zero() { -norm } else { norm };
+    Quaternion { w: q.w / norm, x: q.x / norm, y: q.y / norm, z: q.z / norm }
+}
+
+/// The rotation matrix of a unit [`Rotor3`], rotating column vectors, M v = R v R̃.
+///
+impl<T> From<Rotor3<T>> for Matrix<T, 3, 3>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: Rotor3<T> ) -> Self {
+        let m = rotation_matrix( src.into() );
+        let mut res = Matrix::<T, 3, 3>::default();
+        for row in 0..3 {
+            for col in 0..3 {
+                res[ ( row, col ) ] = m[ row ][ col ];
+            }
+        }
+        res
+    }
+}
+
+/// The unit [`Rotor3`] of a rotation matrix rotating column vectors, with a non-negative scalar part.
+///
+/// Fails with [`Error::NotOrthogonal`] unless Mᵀ M is the identity within √ε and with [`Error::Reflection`] if the
+/// determinant is negative. Use [`Rotor::from_matrix`](crate::rotor::Rotor::from_matrix) for a noisy matrix.
+///
+impl<T> TryFrom<Matrix<T, 3, 3>> for Rotor3<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Error = Error;
+
+    fn try_from( src: Matrix<T, 3, 3> ) -> Result<Self, Self::Error> {
+        let mut m = [ [ T::zero(); 3 ]; 3 ];
+        for row in 0..3 {
+            for col in 0..3 {
+                m[ row ][ col ] = src[ ( row, col ) ];
+            }
+        }
+        if !matrix::is_orthogonal( m, T::epsilon().sqrt() ) {
+            return Err( Error::NotOrthogonal );
+        }
+        if matrix::determinant( m ) < T::zero() {
+            return Err( Error::Reflection );
+        }
+        Ok( rotation_quaternion( m ).into() )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ops::Rotate,
+        test_util::{ EPSILON, assert_same_rotation }
+    };
+
+    #[test]
+    fn quaternion_axis_angle() {
+        let rotor = Rotor3::<f64>::from_axis_angle( Vector::new([ 0.0, 0.0, 1.0 ]), 1.0 );
+
+        let res = Quaternion::from( rotor );
+
+        assert!( ( res.w - 0.5f64.cos() ).abs() < EPSILON );
+        assert!( res.x.abs() < EPSILON && res.y.abs() < EPSILON );
+        assert!( ( res.z - 0.5f64.sin() ).abs() < EPSILON );
+        assert_eq!( Rotor3::from( res ), rotor );
+    }
+
+    #[test]
+    fn quaternion_product() {
+        let lhs = Rotor3::<f64>::from_axis_angle( Vector::new([ 1.0, 2.0, 0.5 ]), 1.1 );
+        let rhs = Rotor3::<f64>::from_axis_angle( Vector::new([ -0.3, 1.0, 2.0 ]), 0.4 );
+        let ( a, b ) = ( Quaternion::from( lhs ), Quaternion::from( rhs ) );
+
+        let res = Quaternion::from( lhs * rhs );
+
+        assert!( ( res.w - ( a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z ) ).abs() < EPSILON );
+        assert!( ( res.x - ( a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y ) ).abs() < EPSILON );
+        assert!( ( res.y - ( a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x ) ).abs() < EPSILON );
+        assert!( ( res.z - ( a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w ) ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn matrix() {
+        let rotor = Rotor3::<f64>::from_axis_angle( Vector::new([ 1.0, 2.0, 0.5 ]), 1.1 );
+
+        let res = Matrix::<f64, 3, 3>::from( rotor );
+
+        for col in 0..3 {
+            let mut axis = Vector::<f64, 3>::default();
+            axis[ col ] = 1.0;
+            let image = rotor.rotate( axis );
+            for row in 0..3 {
+                assert!( ( res[ ( row, col ) ] - image[ row ] ).abs() < EPSILON );
+            }
+        }
+        assert_same_rotation( Rotor3::try_from( res ).unwrap(), rotor );
+    }
+
+    #[test]
+    fn matrix_half_turn() {
+        for axis in [ [ 1.0, 1.0, 0.0 ], [ 0.0, 0.0, 1.0 ], [ 1.0, -2.0, 3.0 ] ] {
+            let rotor = Rotor3::<f64>::from_axis_angle( Vector::new( axis ), std::f64::consts::PI );
+
+            let res = Rotor3::try_from( Matrix::<f64, 3, 3>::from( rotor ) ).unwrap();
+
+            assert_same_rotation( res, rotor );
+        }
+    }
+
+    #[test]
+    fn matrix_reflection() {
+        let mut reflection = Matrix::<f64, 3, 3>::default();
+        reflection[ ( 0, 0 ) ] = -1.0;
+        reflection[ ( 1, 1 ) ] = 1.0;
+        reflection[ ( 2, 2 ) ] = 1.0;
+
+        assert_eq!( Rotor3::try_from( reflection ), Err( Error::Reflection ) );
+    }
+
+    #[test]
+    fn matrix_not_orthogonal() {
+        let mut stretch = Matrix::<f64, 3, 3>::default();
+        stretch[ ( 0, 0 ) ] = 2.0;
+        stretch[ ( 1, 1 ) ] = 1.0;
+        stretch[ ( 2, 2 ) ] = 1.0;
+
+        assert_eq!( Rotor3::try_from( stretch ), Err( Error::NotOrthogonal ) );
+    }
+
+    #[test]
+    fn euler_angles() {
+        let angles = EulerAngles { yaw: 0.7, pitch: -0.4, roll: 2.1 };
+
+        let res = EulerAngles::from( Rotor3::from( angles ) );
+
+        assert!( ( res.yaw - angles.yaw ).abs() < EPSILON );
+        assert!( ( res.pitch - angles.pitch ).abs() < EPSILON );
+        assert!( ( res.roll - angles.roll ).abs() < EPSILON );
+    }
+
+    #[test]
+    fn euler_angles_gimbal_lock() {
+        let angles = EulerAngles { yaw: 0.7, pitch: std::f64::consts::FRAC_PI_2, roll: 0.3 };
+        let rotor = Rotor3::from( angles );
+
+        let res = EulerAngles::from( rotor );
+
+        assert!( ( res.pitch - angles.pitch ).abs() < 1e-6 );
+        assert_same_rotation( Rotor3::from( res ), rotor );
+    }
+}
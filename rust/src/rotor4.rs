@@ -0,0 +1,339 @@
+// Copyright 2024 Bewusstsein Labs
+
+use std::fmt::Debug;
+use num::traits::{ Num, Float };
+
+use linear_algebra::{
+    ops::Magnitude,
+    matrix::Matrix
+};
+
+use crate::{
+    ops::HodgeStar,
+    traits::{
+        ScalarComponent,
+        BiVectorComponent,
+        QuadVectorComponent
+    },
+    error::Error,
+    matrix,
+    bivector::BiVector,
+    quadvector::QuadVector,
+    rotor::Rotor4,
+    rotor3::Quaternion
+};
+
+/// A 4D rotation as two simultaneous rotations in a pair of orthogonal planes.
+///
+/// Every vector in `planes[ i ]` turns by `angles[ i ]` radians within it, the planes are unit bivectors turning like
+/// [`Rotor4::from_plane_angle`]. The first angle is the larger one. A simple rotation has a second angle of zero, its
+/// second plane is then the orthogonal complement of the first. An isoclinic rotation, with equal angles, turns every
+/// plane spanned by a vector and its image the same way, any such pair of planes describes it.
+///
+#[derive( Clone, Copy, Debug, Default, PartialEq )]
+pub struct DoubleRotation<T>
+where
+    T: 'static + Default + Copy + Debug
+{
+    pub planes: [ BiVector<T, 4>; 2 ],
+    pub angles: [ T; 2 ]
+}
+
+impl<T> From<DoubleRotation<T>> for Rotor4<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: DoubleRotation<T> ) -> Self {
+        Rotor4::from_plane_angle( src.planes[ 0 ], src.angles[ 0 ] ) *
+        Rotor4::from_plane_angle( src.planes[ 1 ], src.angles[ 1 ] )
+    }
+}
+
+/// The invariant planes and angles of a unit [`Rotor4`], from the split of its logarithm.
+///
+/// The angles are those of the rotor, in [ 0, 2π ], R and -R differ by 2π in one of them.
+///
+impl<T> From<Rotor4<T>> for DoubleRotation<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: Rotor4<T> ) -> Self {
+        let ( first, second ) = src.log().split();
+        let ( a, b ) = ( first.magnitude(), second.magnitude() );
+        let first = if a > T::zero() {
+            -first / a
+        } else {
+            BiVector::new([ T::one(), T::zero(), T::zero(), T::zero(), T::zero(), T::zero() ])
+        };
+        let second = if b > T::epsilon() { -second / b } else { first.hodge_star() };
+        let two = T::one() + T::one();
+        Self {
+            planes: [ first, second ],
+            angles: [ two * a, two * b ]
+        }
+    }
+}
+
+impl<T> Rotor4<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    /// Creates the [`Rotor4`] rotating v = w + x i + y j + z k to l v r̄, the product of a left and a right isoclinic rotation.
+    ///
+    /// The even subalgebra of 4D splits into two quaternion algebras, on the self dual and the anti self dual
+    /// bivectors, and the rotor is half the sum of both parts. The map is linear and keeps products,
+    /// R( l₁, r₁ ) R( l₂, r₂ ) = R( l₁l₂, r₁r₂ ), a [`Rotor3`](crate::rotor::Rotor3) fixing w has l = r = its [`Quaternion`].
+    ///
+    pub fn from_isoclinic( left: Quaternion<T>, right: Quaternion<T> ) -> Self {
+        let half = T::one() / ( T::one() + T::one() );
+        let ( l, r ) = ( left, right );
+        Rotor4::new_even(
+            ( l.w + r.w ) * half,
+            BiVector::new([
+                -( l.z + r.z ) * half,
+                ( l.y + r.y ) * half,
+                ( l.x - r.x ) * half,
+                -( l.x + r.x ) * half,
+                ( l.y - r.y ) * half,
+                ( l.z - r.z ) * half
+            ]),
+            QuadVector::new([ ( l.w - r.w ) * half ])
+        )
+    }
+
+    /// Returns the left and right isoclinic quaternions l and r with R v R̃ = l v r̄, v = w + x i + y j + z k.
+    ///
+    /// This is the inverse of [`Rotor4::from_isoclinic`], it keeps the sign of the rotor: -R gives -l and -r.
+    ///
+    pub fn isoclinic( &self ) -> ( Quaternion<T>, Quaternion<T> ) {
+        let ( s, b, p ) = ( *self.scalar(), self.bivector(), self.quadvector()[ 0 ] );
+        (
+            Quaternion { w: s + p, x: b[ 2 ] - b[ 3 ], y: b[ 1 ] + b[ 4 ], z: b[ 5 ] - b[ 0 ] },
+            Quaternion { w: s - p, x: -b[ 2 ] - b[ 3 ], y: b[ 1 ] - b[ 4 ], z: -b[ 0 ] - b[ 5 ] }
+        )
+    }
+}
+
+/// Returns the quaternion of the basis vector e_`index` of 4D, x, y, z, w mapping to i, j, k, 1.
+///
+fn basis<T>( index: usize ) -> Quaternion<T>
+where
+    T: Copy + Num
+{
+    let mut q = [ T::zero(); 4 ];
+    q[ ( index + 1 ) % 4 ] = T::one();
+    Quaternion { w: q[ 0 ], x: q[ 1 ], y: q[ 2 ], z: q[ 3 ] }
+}
+
+/// Returns the components w, x, y, z of the quaternion `q`.
+///
+fn components<T>( q: Quaternion<T> ) -> [ T; 4 ] {
+    [ q.w, q.x, q.y, q.z ]
+}
+
+/// The rotation matrix of a unit [`Rotor4`], rotating column vectors, M v = R v R̃.
+///
+impl<T> From<Rotor4<T>> for Matrix<T, 4, 4>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    fn from( src: Rotor4<T> ) -> Self {
+        let ( left, right ) = src.isoclinic();
+        let right = right.conjugate();
+        let mut res = Matrix::<T, 4, 4>::default();
+        for col in 0..4 {
+            let image = left * basis( col ) * right;
+            let image = [ image.x, image.y, image.z, image.w ];
+            for row in 0..4 {
+                res[ ( row, col ) ] = image[ row ];
+            }
+        }
+        res
+    }
+}
+
+/// The unit [`Rotor4`] of a rotation matrix rotating column vectors, with a non-negative scalar part.
+///
+/// Van Elfrinkhof's method: the matrix determines the outer product l rᵀ of the isoclinic quaternions, see
+/// [`Rotor4::isoclinic`], r is its largest row and l its product with r. Fails with [`Error::NotOrthogonal`] unless
+/// Mᵀ M is the identity within √ε and with [`Error::Reflection`] if the determinant is negative. Use
+/// [`Rotor::from_matrix`](crate::rotor::Rotor::from_matrix) for a noisy matrix.
+///
+impl<T> TryFrom<Matrix<T, 4, 4>> for Rotor4<T>
+where
+    T: 'static + Copy + Default + Debug + Num + Float
+{
+    type Error = Error;
+
+    fn try_from( src: Matrix<T, 4, 4> ) -> Result<Self, Self::Error> {
+        let mut m = [ [ T::zero(); 4 ]; 4 ];
+        for row in 0..4 {
+            for col in 0..4 {
+                m[ row ][ col ] = src[ ( row, col ) ];
+            }
+        }
+        if !matrix::is_orthogonal( m, T::epsilon().sqrt() ) {
+            return Err( Error::NotOrthogonal );
+        }
+        if matrix::determinant( m ) < T::zero() {
+            return Err( Error::Reflection );
+        }
+        let quarter = T::one() / ( T::one() + T::one() + T::one() + T::one() );
+        let columns: [ Quaternion<T>; 4 ] = std::array::from_fn( |col| Quaternion { w: m[ 3 ][ col ], x: m[ 0 ][ col ], y: m[ 1 ][ col ], z: m[ 2 ][ col ] } );
+        let unit = |index: usize| basis::<T>( ( index + 3 ) % 4 );
+        // Associate matrix, l_a r_b = ¼ Σ_j < M e_j, u_a e_j ū_b >
+        let associate: [ [ T; 4 ]; 4 ] = std::array::from_fn( |a| std::array::from_fn( |b| {
+            ( 0..4 ).fold( T::zero(), |acc, col| {
+                let image = components( unit( a ) * basis( col ) * unit( b ).conjugate() );
+                components( columns[ col ] ).iter().zip( image.iter() ).fold( acc, |acc, ( &x, &y )| acc + x * y )
+            } ) * quarter
+        } ) );
+        let norm = |q: [ T; 4 ]| q.iter().fold( T::zero(), |acc, &x| acc + x * x ).sqrt();
+        let row = ( 0..4 ).fold( 0, |best, row| if norm( associate[ row ] ) > norm( associate[ best ] ) { row } else { best } );
+        let row_norm = norm( associate[ row ] );
+        let right = associate[ row ].map( |x| x / row_norm );
+        let left: [ T; 4 ] = std::array::from_fn( |a| associate[ a ].iter().zip( right.iter() ).fold( T::zero(), |acc, ( &x, &y )| acc + x * y ) );
+        let left_norm = norm( left );
+        let left = left.map( |x| x / left_norm );
+        let quaternion = |q: [ T; 4 ]| Quaternion { w: q[ 0 ], x: q[ 1 ], y: q[ 2 ], z: q[ 3 ] };
+        let res = Rotor4::from_isoclinic( quaternion( left ), quaternion( right ) );
+        if *res.scalar() < T::zero() {
+            Ok( Rotor4::new_even( -*res.scalar(), -*res.bivector(), -*res.quadvector() ) )
+        } else {
+            Ok( res )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linear_algebra::vector::Vector;
+    use crate::{
+        ops::Rotate,
+        test_util::{ EPSILON, assert_same_rotation }
+    };
+
+    fn rotor() -> Rotor4<f64> {
+        Rotor4::from_plane_angle( BiVector::new([ 1.0, 0.5, 0.0, 0.3, 0.0, 0.0 ]), 0.9 ) *
+        Rotor4::from_plane_angle( BiVector::new([ 0.6, 0.2, 1.0, -0.4, 0.1, 0.7 ]), 2.3 )
+    }
+
+    #[test]
+    fn isoclinic() {
+        let rotor = rotor();
+        let ( left, right ) = rotor.isoclinic();
+        let v = Vector::<f64, 4>::new([ 0.3, -1.2, 0.7, 2.0 ]);
+
+        let res = rotor.rotate( v );
+        let expected = left * Quaternion { w: v[ 3 ], x: v[ 0 ], y: v[ 1 ], z: v[ 2 ] } * right.conjugate();
+
+        assert!( ( res[ 0 ] - expected.x ).abs() < EPSILON );
+        assert!( ( res[ 1 ] - expected.y ).abs() < EPSILON );
+        assert!( ( res[ 2 ] - expected.z ).abs() < EPSILON );
+        assert!( ( res[ 3 ] - expected.w ).abs() < EPSILON );
+        assert_same_rotation( Rotor4::from_isoclinic( left, right ), rotor );
+    }
+
+    #[test]
+    fn isoclinic_product() {
+        let ( lhs, rhs ) = ( rotor(), Rotor4::from_plane_angle( BiVector::new([ 0.0, 1.0, 0.0, 0.0, 0.0, 1.0 ]), 0.6 ) );
+        let ( a, b ) = ( lhs.isoclinic(), rhs.isoclinic() );
+
+        let res = Rotor4::from_isoclinic( a.0 * b.0, a.1 * b.1 );
+
+        assert_same_rotation( res, lhs * rhs );
+    }
+
+    #[test]
+    fn matrix() {
+        let rotor = rotor();
+
+        let res = Matrix::<f64, 4, 4>::from( rotor );
+
+        for col in 0..4 {
+            let mut axis = Vector::<f64, 4>::default();
+            axis[ col ] = 1.0;
+            let image = rotor.rotate( axis );
+            for row in 0..4 {
+                assert!( ( res[ ( row, col ) ] - image[ row ] ).abs() < EPSILON );
+            }
+        }
+        assert_same_rotation( Rotor4::try_from( res ).unwrap(), rotor );
+    }
+
+    #[test]
+    fn matrix_half_turns() {
+        let half_turn = std::f64::consts::PI;
+        for rotor in [
+            Rotor4::<f64>::from_plane_angle( BiVector::new([ 1.0, 0.0, 0.0, 0.0, 0.0, 0.0 ]), half_turn ),
+            Rotor4::from_plane_angle( BiVector::new([ 1.0, 0.0, 0.0, 0.0, 0.0, 0.0 ]), half_turn ) *
+            Rotor4::from_plane_angle( BiVector::new([ 0.0, 0.0, 0.0, 0.0, 0.0, 1.0 ]), half_turn )
+        ] {
+            let res = Rotor4::try_from( Matrix::<f64, 4, 4>::from( rotor ) ).unwrap();
+
+            assert_same_rotation( res, rotor );
+        }
+    }
+
+    #[test]
+    fn matrix_reflection() {
+        let mut reflection = Matrix::<f64, 4, 4>::default();
+        reflection[ ( 0, 0 ) ] = 1.0;
+        reflection[ ( 1, 1 ) ] = 1.0;
+        reflection[ ( 2, 2 ) ] = 1.0;
+        reflection[ ( 3, 3 ) ] = -1.0;
+
+        assert_eq!( Rotor4::try_from( reflection ), Err( Error::Reflection ) );
+    }
+
+    #[test]
+    fn matrix_not_orthogonal() {
+        let mut shear = Matrix::<f64, 4, 4>::default();
+        for i in 0..4 {
+            shear[ ( i, i ) ] = 1.0;
+        }
+        shear[ ( 0, 3 ) ] = 0.5;
+
+        assert_eq!( Rotor4::try_from( shear ), Err( Error::NotOrthogonal ) );
+    }
+
+    #[test]
+    fn double_rotation() {
+        let xy = BiVector::<f64, 4>::new([ 1.0, 0.0, 0.0, 0.0, 0.0, 0.0 ]);
+        let zw = BiVector::<f64, 4>::new([ 0.0, 0.0, 0.0, 0.0, 0.0, 1.0 ]);
+        let rotor = Rotor4::from_plane_angle( zw, 0.4 ) * Rotor4::from_plane_angle( xy, 1.3 );
+
+        let res = DoubleRotation::from( rotor );
+
+        assert!( ( res.angles[ 0 ] - 1.3 ).abs() < EPSILON );
+        assert!( ( res.angles[ 1 ] - 0.4 ).abs() < EPSILON );
+        assert!( ( res.planes[ 0 ] - xy ).magnitude() < EPSILON );
+        assert!( ( res.planes[ 1 ] - zw ).magnitude() < EPSILON );
+        assert_same_rotation( Rotor4::from( res ), rotor );
+    }
+
+    #[test]
+    fn double_rotation_simple() {
+        let plane = BiVector::<f64, 4>::new([ 0.0, 0.6, 0.0, 0.8, 0.0, 0.0 ]);
+        let rotor = Rotor4::from_plane_angle( plane, 0.7 );
+
+        let res = DoubleRotation::from( rotor );
+
+        assert!( ( res.angles[ 0 ] - 0.7 ).abs() < EPSILON );
+        assert!( res.angles[ 1 ].abs() < EPSILON );
+        assert!( ( res.planes[ 0 ] - plane ).magnitude() < EPSILON );
+        assert!( ( res.planes[ 1 ].magnitude() - 1.0 ).abs() < EPSILON );
+        assert_same_rotation( Rotor4::from( res ), rotor );
+    }
+
+    #[test]
+    fn double_rotation_non_simple() {
+        let rotor = rotor();
+
+        let res = DoubleRotation::from( rotor );
+
+        assert!( res.angles[ 0 ] >= res.angles[ 1 ] );
+        assert_same_rotation( Rotor4::from( res ), rotor );
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright 2024 Bewusstsein Labs
+
+use std::fmt::Debug;
+
+use crate::{
+    blade::binomial,
+    kvector::KVector
+};
+
+/// A scalar type of generic element and size.
+///
+/// The grade 0 [`KVector`], it has a single component. Lets scalars take part in the graded products
+/// alongside the other k-vectors.
+///
+pub type Scalar<T, const DIM: usize> = KVector<T, DIM, 0>;
+
+impl<T, const DIM: usize> From<T> for Scalar<T, DIM>
+where
+    T: 'static + Copy + Default + Debug,
+    [(); binomial( DIM, 0 )]:
+{
+    fn from( src: T ) -> Self {
+        let mut res = Self::default();
+        res[ 0 ] = src;
+        res
+    }
+}
+
+pub type Scalar2<T> = Scalar<T, 2>;
+pub type Scalar3<T> = Scalar<T, 3>;
+pub type Scalar4<T> = Scalar<T, 4>;
@@ -0,0 +1,56 @@
+// Copyright 2024 Bewusstsein Labs
+
+use linear_algebra::vector::Vector;
+
+use crate::{
+    traits::{
+        ScalarComponent,
+        BiVectorComponent,
+        QuadVectorComponent
+    },
+    blade::binomial,
+    metric::Metric,
+    rotor::Rotor
+};
+
+/// The tolerance the floating point tests compare against.
+///
+pub(crate) const EPSILON: f64 = 1e-12;
+
+/// Asserts that every part of `rotor` is within `EPSILON` of the same part of `expected`.
+///
+pub(crate) fn assert_rotor<const DIM: usize, M>( rotor: Rotor<f64, DIM, M>, expected: Rotor<f64, DIM, M> )
+where
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    assert!( ( rotor.scalar() - expected.scalar() ).abs() < EPSILON, "{rotor:?} != {expected:?}" );
+    assert!( rotor.bivector().iter().zip( expected.bivector().iter() ).all( |( a, b )| ( a - b ).abs() < EPSILON ), "{rotor:?} != {expected:?}" );
+    assert!( rotor.quadvector().iter().zip( expected.quadvector().iter() ).all( |( a, b )| ( a - b ).abs() < EPSILON ), "{rotor:?} != {expected:?}" );
+}
+
+/// Asserts that `rotor` and `expected` are the same rotation, i.e. equal up to the sign R and -R share.
+///
+pub(crate) fn assert_same_rotation<const DIM: usize, M>( rotor: Rotor<f64, DIM, M>, expected: Rotor<f64, DIM, M> )
+where
+    M: Metric,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 4 )]:
+{
+    let dot = rotor.scalar() * expected.scalar()
+        + rotor.bivector().iter().zip( expected.bivector().iter() ).fold( 0.0, |acc, ( a, b )| acc + a * b )
+        + rotor.quadvector().iter().zip( expected.quadvector().iter() ).fold( 0.0, |acc, ( a, b )| acc + a * b );
+    if dot < 0.0 {
+        assert_rotor( rotor, Rotor::new_even( -expected.scalar(), -*expected.bivector(), -*expected.quadvector() ) );
+    } else {
+        assert_rotor( rotor, expected );
+    }
+}
+
+/// Asserts that `position`, the position of a finite point, is within `EPSILON` of `expected` in every coordinate.
+///
+pub(crate) fn assert_position<const N: usize>( position: Option<Vector<f64, N>>, expected: [ f64; N ] ) {
+    let position = position.unwrap();
+    assert!( ( 0..N ).all( |i| ( position[ i ] - expected[ i ] ).abs() < EPSILON ), "{position:?} != {expected:?}" );
+}
@@ -3,8 +3,10 @@
 use linear_algebra::vector::Vector;
 
 use crate::{
+    blade::binomial,
     bivector::BiVector,
-    trivector::TriVector
+    trivector::TriVector,
+    quadvector::QuadVector
 };
 
 pub trait XY<T> {
@@ -56,7 +58,7 @@ where
 pub trait BiVectorComponent<T, const DIM: usize>
 where
     T: 'static + Copy + Default + std::fmt::Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     fn bivector( &self ) -> &BiVector<T, DIM>;
 }
@@ -64,7 +66,7 @@ where
 pub trait BiVectorComponentMut<T, const DIM: usize>
 where
     T: 'static + Copy + Default + std::fmt::Debug,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:
 {
     fn bivector_mut( &mut self ) -> &mut BiVector<T, DIM>;
 }
@@ -72,6 +74,7 @@ where
 pub trait TriVectorComponent<T, const DIM: usize>
 where
     T: 'static + Copy + Default + std::fmt::Debug,
+    [(); binomial( DIM, 3 )]:
 {
     fn trivector( &self ) -> &TriVector<T, DIM>;
 }
@@ -79,6 +82,23 @@ where
 pub trait TriVectorComponentMut<T, const DIM: usize>
 where
     T: 'static + Copy + Default + std::fmt::Debug,
+    [(); binomial( DIM, 3 )]:
 {
     fn trivector_mut( &mut self ) -> &mut TriVector<T, DIM>;
 }
+
+pub trait QuadVectorComponent<T, const DIM: usize>
+where
+    T: 'static + Copy + Default + std::fmt::Debug,
+    [(); binomial( DIM, 4 )]:
+{
+    fn quadvector( &self ) -> &QuadVector<T, DIM>;
+}
+
+pub trait QuadVectorComponentMut<T, const DIM: usize>
+where
+    T: 'static + Copy + Default + std::fmt::Debug,
+    [(); binomial( DIM, 4 )]:
+{
+    fn quadvector_mut( &mut self ) -> &mut QuadVector<T, DIM>;
+}
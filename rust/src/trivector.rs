@@ -1,197 +1,33 @@
 // Copyright 2024 Bewusstsein Labs
 
-use std::{
-    fmt::Debug,
-    ops::{ Neg, Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign }
-};
+use std::ops::{ Neg, Mul, Sub };
 use num::traits::Num;
 
 use linear_algebra::vector::Vector;
 
 use crate::{
     ops::{
-        //InteriorProduct,
-        ExteriorProduct,
         GeometricAdd,
         GeometricSub,
         GeometricProduct,
     },
-    bivector::BiVector
+    blade::binomial,
+    kvector::KVector,
+    bivector::BiVector,
+    multivector::Multivector
 };
 
-/// A vector type of generic element and size.
+/// A trivector type of generic element and size.
 ///
-#[derive( Clone, Copy, Debug, Default )]
-pub struct TriVector<T, const DIM: usize>( T )
-where
-    T: 'static + Default + Copy + Debug;
-
-impl<T, const DIM: usize> TriVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug,
-{
-    /// Creates a new const [`TriVector`].
-    ///
-    pub const fn new_const( src: T ) -> Self {
-        Self ( src )
-    }
-
-    /// Creates a new [`TriVector`].
-    ///
-    pub fn new( src: T ) -> Self {
-        Self ( src )
-    }
-
-    /// Creates a new zero filled [`TriVector`].
-    ///
-    pub fn zero() -> Self
-    where
-        T: Num
-    {
-        Self ( T::zero() )
-    }
-}
-
-impl<T, const DIM: usize> Deref for TriVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug,
-{
-    type Target = T;
-
-    fn deref( &self ) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl<T, const DIM: usize> DerefMut for TriVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug,
-{
-    fn deref_mut( &mut self ) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
-impl<T, const DIM: usize> PartialEq for TriVector<T, DIM>
-where
-    T: 'static + Copy + Default + Debug + PartialEq,
-{
-    fn eq( &self, other: &Self ) -> bool {
-        self.0 == other.0
-    }
-}
-
-impl<T, const DIM: usize> Neg for TriVector<T, DIM>
-where
-    T: Default + Copy + Debug + Neg<Output = T>,
-{
-    type Output = Self;
-
-    fn neg( mut self ) -> Self::Output {
-        self.0 = -self.0;
-        self
-    }
-}
-
-impl<T, const DIM: usize> Add for TriVector<T, DIM>
-where
-    T: Default + Copy + Debug + Add<Output = T>,
-    Self: Clone
-{
-    type Output = Self;
-
-    fn add( mut self, other: Self ) -> Self::Output {
-        self.0 = self.0 + other.0;
-        self
-    }
-}
-
-impl<T, const DIM: usize> Sub for TriVector<T, DIM>
-where
-    T: Default + Copy + Debug + Sub<Output = T>,
-    Self: Clone
-{
-    type Output = Self;
-
-    fn sub( mut self, other: Self ) -> Self::Output {
-        self.0 = self.0 - other.0;
-        self
-    }
-}
-
-impl<T, const DIM: usize> Add<T> for TriVector<T, DIM>
-where
-    T: Default + Copy + Debug + Add<Output = T>,
-    Self: Clone
-{
-    type Output = Self;
-
-    fn add( mut self, other: T ) -> Self::Output {
-        self.0 = self.0 + other;
-        self
-    }
-}
-
-impl<T, const DIM: usize> Sub<T> for TriVector<T, DIM>
-where
-    T: Default + Copy + Debug + Sub<Output = T>,
-    Self: Clone
-{
-    type Output = Self;
-
-    fn sub( mut self, other: T ) -> Self::Output {
-        self.0 = self.0 - other;
-        self
-    }
-}
-
-impl<T, const DIM: usize> Mul<T> for TriVector<T, DIM>
-where
-    T: Default + Copy + Debug + Mul<Output = T>,
-    Self: Clone
-{
-    type Output = Self;
-
-    fn mul( mut self, other: T ) -> Self::Output {
-        self.0 = self.0 * other;
-        self
-    }
-}
-
-impl<T, const DIM: usize> Div<T> for TriVector<T, DIM>
-where
-    T: Default + Copy + Debug + Div<Output = T>,
-    Self: Clone
-{
-    type Output = Self;
-
-    fn div( mut self, other: T ) -> Self::Output {
-        self.0 = self.0 / other;
-        self
-    }
-}
-
-impl<T, const DIM: usize> AddAssign for TriVector<T, DIM>
-where
-    T: Default + Copy + Debug + AddAssign
-{
-    fn add_assign( &mut self, other: Self ) {
-        self.0 += other.0;
-    }
-}
-
-impl<T, const DIM: usize> SubAssign for TriVector<T, DIM>
-where
-    T: Default + Copy + Debug + SubAssign
-{
-    fn sub_assign( &mut self, other: Self ) {
-        self.0 -= other.0;
-    }
-}
+/// Stores one component per grade 3 basis blade, C(DIM, 3) in total, ordered lexicographically,
+/// e.g. xyz, xyw, xzw, yzw in 4D.
+///
+pub type TriVector<T, const DIM: usize> = KVector<T, DIM, 3>;
 
 impl<T, const DIM: usize> GeometricAdd<Vector<T, DIM>> for TriVector<T, DIM>
 where
-    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Num
+    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Num,
+    [(); binomial( DIM, 3 )]:
 {
     type Output = ( Vector<T, DIM>, TriVector<T, DIM> );
 
@@ -202,7 +38,8 @@ where
 
 impl<T, const DIM: usize> GeometricSub<Vector<T, DIM>> for TriVector<T, DIM>
 where
-    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Num
+    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Num,
+    [(); binomial( DIM, 3 )]:
 {
     type Output = ( Vector<T, DIM>, TriVector<T, DIM> );
 
@@ -214,7 +51,8 @@ where
 impl<T, const DIM: usize> GeometricAdd<BiVector<T, DIM>> for TriVector<T, DIM>
 where
     T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Num,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 3 )]:
 {
     type Output = ( BiVector<T, DIM>, TriVector<T, DIM> );
 
@@ -226,7 +64,8 @@ where
 impl<T, const DIM: usize> GeometricSub<BiVector<T, DIM>> for TriVector<T, DIM>
 where
     T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Num,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 3 )]:
 {
     type Output = ( BiVector<T, DIM>, TriVector<T, DIM> );
 
@@ -235,49 +74,58 @@ where
     }
 }
 
+/// TriVector<T, DIM> * Vector<T, DIM>
+///
+/// Grade 2 and, from 4D on, grade 4.
+///
 impl<T, const DIM: usize> GeometricProduct<Vector<T, DIM>> for TriVector<T, DIM>
 where
-    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Num,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    T: Default + std::fmt::Debug + Copy + Num,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 3 )]:
 {
-    type Output = BiVector<T, DIM>;
+    type Output = Multivector<T, DIM>;
 
     fn geometric_product( self, rhs: Vector<T, DIM> ) -> Self::Output {
-        let mut res = BiVector::<T, DIM>::default();
-        res.iter_mut().zip( rhs.iter() ).for_each( |( res, &rhs )| {
-            *res = self.0 * rhs;
-        });
-        res
+        Multivector::from( self ).geometric_product( rhs )
     }
 }
 
+/// TriVector<T, DIM> * BiVector<T, DIM>
+///
+/// Grade 1, from 4D on grade 3 and from 5D on grade 5.
+///
 impl<T, const DIM: usize> GeometricProduct<BiVector<T, DIM>> for TriVector<T, DIM>
 where
-    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Num,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    T: Default + std::fmt::Debug + Copy + Num,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 2 )]:,
+    [(); binomial( DIM, 3 )]:
 {
-    type Output = Vector<T, { DIM * ( DIM - 1 ) / 2 }>;
+    type Output = Multivector<T, DIM>;
 
     fn geometric_product( self, rhs: BiVector<T, DIM> ) -> Self::Output {
-        let mut res = Vector::<T, { DIM * ( DIM - 1 ) / 2 }>::default();
-        res.iter_mut().zip( rhs.iter() ).for_each( |( res, &rhs )| {
-            *res = self.0 * rhs;
-        });
-        -res
+        Multivector::from( self ).geometric_product( rhs )
     }
 }
 
+/// TriVector<T, DIM> * TriVector<T, DIM>
+///
+/// Grade 0, from 4D on grade 2, from 5D on grade 4 and from 6D on grade 6.
+///
 impl<T, const DIM: usize> GeometricProduct for TriVector<T, DIM>
 where
-    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Num,
-    [(); DIM * ( DIM - 1 ) / 2 ]:
+    T: Default + std::fmt::Debug + Copy + Num,
+    [(); 1 << DIM]:,
+    [(); binomial( DIM, 3 )]:
 {
-    type Output = T;
+    type Output = Multivector<T, DIM>;
 
     fn geometric_product( self, rhs: TriVector<T, DIM> ) -> Self::Output {
-        -self.0 * rhs.0
+        Multivector::from( self ).geometric_product( rhs )
     }
 }
 
 pub type TriVector3<T> = TriVector<T, 3>;
 pub type TriVector4<T> = TriVector<T, 4>;
+pub type TriVector5<T> = TriVector<T, 5>;
@@ -1,6 +1,6 @@
 use std::{
     fmt::Debug,
-    ops::{ Neg, Sub, Mul, Div, AddAssign }
+    ops::{ Neg, Sub, Mul, Div }
 };
 
 use num::traits::{ Num, Float };
@@ -14,22 +14,26 @@ use linear_algebra::{
 
 use crate::{
     ops::{
-        //InteriorProduct,
+        LeftContraction,
+        RightContraction,
+        HestenesInnerProduct,
+        FatDot,
+        ScalarProduct,
+        Reverse,
+        GradeInvolution,
+        CliffordConjugate,
         ExteriorProduct,
-        GeometricAdd,
-        GeometricSub,
         GeometricProduct
     },
-    bivector::BiVector,
-    trivector::TriVector,
-    rotor::Rotor,
+    blade::binomial,
+    bivector::BiVector
 };
 
 use crate::ops::GeometricInverse;
 
 impl<T, const COL: usize> GeometricInverse for Vector<T, COL>
 where
-    T: Default + Copy + Debug + Div<Output = T> + Num + Float
+    T: 'static + Default + Copy + Debug + Div<Output = T> + Num + Float
 {
     type Output = Vector<T, COL>;
 
@@ -38,23 +42,45 @@ where
     }
 }
 
-/*
-impl<T, const COL: usize> InteriorProduct for Vector<T, COL>
+impl<T, const COL: usize> Reverse for Vector<T, COL>
 where
-    T: Default + Debug + Copy
+    T: 'static + Default + std::fmt::Debug + Copy
 {
-    type Output;
+    type Output = Vector<T, COL>;
 
-    fn interior_product( self, rhs: Self ) -> Self::Output {
+    fn reverse( self ) -> Self::Output {
+        self
+    }
+}
+
+impl<T, const COL: usize> GradeInvolution for Vector<T, COL>
+where
+    T: 'static + Default + std::fmt::Debug + Copy + Neg<Output = T>,
+    Vector<T, COL>: Neg<Output = Vector<T, COL>>
+{
+    type Output = Vector<T, COL>;
 
+    fn grade_involution( self ) -> Self::Output {
+        -self
+    }
+}
+
+impl<T, const COL: usize> CliffordConjugate for Vector<T, COL>
+where
+    T: 'static + Default + std::fmt::Debug + Copy + Neg<Output = T>,
+    Vector<T, COL>: Neg<Output = Vector<T, COL>>
+{
+    type Output = Vector<T, COL>;
+
+    fn clifford_conjugate( self ) -> Self::Output {
+        -self
     }
 }
-*/
 
 impl<T, const COL: usize> ExteriorProduct<Vector<T, COL>> for Vector<T, COL>
 where
-    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T>,
-    [(); COL * ( COL - 1 ) / 2 ]:
+    T: 'static + Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T>,
+    [(); binomial( COL, 2 )]:
 {
     type Output = BiVector<T, COL>;
 
@@ -71,23 +97,81 @@ where
     }
 }
 
-impl<T, const COL: usize> ExteriorProduct<BiVector<T, COL>> for Vector<T, COL>
+/// Vector<T, COL> ⌋ Vector<T, COL>
+///
+impl<T, const COL: usize> LeftContraction<Vector<T, COL>> for Vector<T, COL>
+where
+    T: Default + std::fmt::Debug + Copy + Num
+{
+    type Output = T;
+
+    fn left_contraction( self, rhs: Vector<T, COL> ) -> Self::Output {
+        self.iter().zip( rhs.iter() )
+            .fold( T::zero(), |acc, ( &a, &b )| acc + a * b )
+    }
+}
+
+/// Vector<T, COL> ⌊ Vector<T, COL>
+///
+impl<T, const COL: usize> RightContraction<Vector<T, COL>> for Vector<T, COL>
+where
+    T: Default + std::fmt::Debug + Copy + Num
+{
+    type Output = T;
+
+    fn right_contraction( self, rhs: Vector<T, COL> ) -> Self::Output {
+        self.iter().zip( rhs.iter() )
+            .fold( T::zero(), |acc, ( &a, &b )| acc + a * b )
+    }
+}
+
+/// Vector<T, COL> · Vector<T, COL>
+///
+impl<T, const COL: usize> HestenesInnerProduct<Vector<T, COL>> for Vector<T, COL>
+where
+    T: Default + std::fmt::Debug + Copy + Num
+{
+    type Output = T;
+
+    fn hestenes_inner_product( self, rhs: Vector<T, COL> ) -> Self::Output {
+        self.iter().zip( rhs.iter() )
+            .fold( T::zero(), |acc, ( &a, &b )| acc + a * b )
+    }
+}
+
+/// Vector<T, COL> • Vector<T, COL>
+///
+impl<T, const COL: usize> FatDot<Vector<T, COL>> for Vector<T, COL>
+where
+    T: Default + std::fmt::Debug + Copy + Num
+{
+    type Output = T;
+
+    fn fat_dot( self, rhs: Vector<T, COL> ) -> Self::Output {
+        self.iter().zip( rhs.iter() )
+            .fold( T::zero(), |acc, ( &a, &b )| acc + a * b )
+    }
+}
+
+/// Vector<T, COL> * Vector<T, COL>
+///
+impl<T, const COL: usize> ScalarProduct<Vector<T, COL>> for Vector<T, COL>
 where
-    T: Default + std::fmt::Debug + Copy + AddAssign + Mul<Output = T>,
-    [(); COL * ( COL - 1 ) / 2 ]:
+    T: Default + std::fmt::Debug + Copy + Num
 {
-    type Output = TriVector<T, COL>;
+    type Output = T;
 
-    fn exterior_product( self, rhs: BiVector<T, COL> ) -> Self::Output {
-        rhs.exterior_product( self )
+    fn scalar_product( self, rhs: Vector<T, COL> ) -> Self::Output {
+        self.iter().zip( rhs.iter() )
+            .fold( T::zero(), |acc, ( &a, &b )| acc + a * b )
     }
 }
 
 impl<T, const COL: usize> GeometricProduct<Vector<T, COL>> for Vector<T, COL>
 where
-    T: Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Num,
+    T: 'static + Default + std::fmt::Debug + Copy + Sub<Output = T> + Mul<Output = T> + Num,
     Self: InnerProduct<Vector<T, COL>, Output = T> + ExteriorProduct<Vector<T, COL>, Output = BiVector<T, COL>>,
-    [(); COL * ( COL - 1 ) / 2 ]:
+    [(); binomial( COL, 2 )]:
 {
     type Output = ( T, BiVector<T, COL> );
 